@@ -0,0 +1,57 @@
+//! Compare the SIMD operators with the generic `combine` path.
+//!
+//! Run with `cargo +nightly bench --features simd`.
+
+#![feature(test)]
+
+extern crate test;
+
+use isochro::ops::DotProduct;
+use isochro::vector::Vec;
+use test::{Bencher, black_box};
+
+fn operands() -> (Vec<8, f32>, Vec<8, f32>) {
+    (
+        Vec(core::array::from_fn(|i| i as f32 * 0.5)),
+        Vec(core::array::from_fn(|i| 3.0 - i as f32)),
+    )
+}
+
+#[bench]
+fn add_simd(bencher: &mut Bencher) {
+    let (a, b) = operands();
+    bencher.iter(|| black_box(a) + black_box(b));
+}
+
+#[bench]
+fn add_scalar(bencher: &mut Bencher) {
+    let (a, b) = operands();
+    bencher.iter(|| black_box(a).combine(black_box(b), |a, b| a + b));
+}
+
+#[bench]
+fn mul_scalar_simd(bencher: &mut Bencher) {
+    let (a, _) = operands();
+    bencher.iter(|| black_box(a) * black_box(1.5));
+}
+
+#[bench]
+fn mul_scalar_scalar(bencher: &mut Bencher) {
+    let (a, _) = operands();
+    bencher.iter(|| black_box(a).combine_scalar(black_box(1.5), |a, b| a * b));
+}
+
+#[bench]
+fn dot_simd(bencher: &mut Bencher) {
+    let (a, b) = operands();
+    bencher.iter(|| black_box(a).dot(black_box(b)));
+}
+
+#[bench]
+fn dot_scalar(bencher: &mut Bencher) {
+    let (a, b) = operands();
+    bencher.iter(|| {
+        let (a, b) = (black_box(a), black_box(b));
+        a.0.iter().zip(b.0).map(|(a, b)| a * b).sum::<f32>()
+    });
+}
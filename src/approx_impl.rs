@@ -0,0 +1,128 @@
+//! Integration with the `approx` crate, enabled by the `approx` feature.
+//!
+//! Vectors and matrices are approximately equal when every pair of
+//! components is.
+
+use core::iter::zip;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::matrix::Mat;
+use crate::vector::Vec;
+
+impl<T: AbsDiffEq, const D: usize> AbsDiffEq for Vec<D, T>
+where
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        zip(&self.0, &other.0).all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl<T: RelativeEq, const D: usize> RelativeEq for Vec<D, T>
+where
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        zip(&self.0, &other.0).all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl<T: UlpsEq, const D: usize> UlpsEq for Vec<D, T>
+where
+    T::Epsilon: Copy,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        zip(&self.0, &other.0).all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl<T: AbsDiffEq, const M: usize, const N: usize> AbsDiffEq for Mat<M, N, T>
+where
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        zip(&self.0, &other.0).all(|(a, b)| AbsDiffEq::abs_diff_eq(a, b, epsilon))
+    }
+}
+
+impl<T: RelativeEq, const M: usize, const N: usize> RelativeEq for Mat<M, N, T>
+where
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        zip(&self.0, &other.0).all(|(a, b)| RelativeEq::relative_eq(a, b, epsilon, max_relative))
+    }
+}
+
+impl<T: UlpsEq, const M: usize, const N: usize> UlpsEq for Mat<M, N, T>
+where
+    T::Epsilon: Copy,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        zip(&self.0, &other.0).all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq};
+
+    use crate::matrix::Mat;
+    use crate::vector::Vec2;
+
+    #[test]
+    fn test_vec_approx() {
+        let a = Vec2::new(0.1_f64 + 0.2, 1.0);
+        let b = Vec2::new(0.3, 1.0);
+        assert_abs_diff_eq!(a, b);
+        assert_relative_eq!(a, b);
+        assert_ulps_eq!(a, b);
+        assert!(approx::abs_diff_ne!(a, Vec2::new(0.3, 1.1)));
+    }
+
+    #[test]
+    fn test_mat_approx() {
+        let a = Mat([Vec2::new(0.1_f64 + 0.2, 1.0), Vec2::new(2.0, 3.0)]);
+        let b = Mat([Vec2::new(0.3, 1.0), Vec2::new(2.0, 3.0)]);
+        assert_relative_eq!(a, b);
+    }
+}
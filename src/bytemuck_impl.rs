@@ -0,0 +1,49 @@
+//! Integration with the `bytemuck` crate, enabled by the `bytemuck` feature.
+//!
+//! `Vec<D, T>` is `#[repr(transparent)]` over `[T; D]` and `Mat<M, N, T>` over
+//! `[Vec<N, T>; M]`, so they have the layout of the arrays and inherit the
+//! `Zeroable` and `Pod` guarantees of `T`. This allows casting slices of vectors
+//! or matrices to bytes, to upload them into GPU buffers for example.
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::matrix::Mat;
+use crate::vector::Vec;
+
+// SAFETY: `Vec` is `#[repr(transparent)]` over `[T; D]`, which is `Zeroable`
+unsafe impl<T: Zeroable, const D: usize> Zeroable for Vec<D, T> {}
+
+// SAFETY: `Vec` is `#[repr(transparent)]` over `[T; D]`, which is `Pod`
+unsafe impl<T: Pod, const D: usize> Pod for Vec<D, T> {}
+
+// SAFETY: `Mat` is `#[repr(transparent)]` over `[Vec<N, T>; M]`, which is `Zeroable`
+unsafe impl<T: Zeroable, const M: usize, const N: usize> Zeroable for Mat<M, N, T> {}
+
+// SAFETY: `Mat` is `#[repr(transparent)]` over `[Vec<N, T>; M]`, which is `Pod`
+unsafe impl<T: Pod, const M: usize, const N: usize> Pod for Mat<M, N, T> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Mat;
+    use crate::vector::{Vec2, Vec3};
+
+    #[test]
+    fn test_vec_slice_round_trip() {
+        let vecs = [Vec3::new(1.0_f32, 2.0, 3.0), Vec3::new(-4.0, 5.5, 0.0)];
+        let bytes: &[u8] = bytemuck::cast_slice(&vecs);
+        assert_eq!(bytes.len(), 2 * 3 * 4);
+        assert_eq!(&bytes[4..8], &2.0_f32.to_ne_bytes());
+        assert_eq!(bytemuck::cast_slice::<u8, Vec3<f32>>(bytes), &vecs);
+    }
+
+    #[test]
+    fn test_mat_cast() {
+        let mat = Mat([Vec2::new(1_u32, 2), Vec2::new(3, 4)]);
+        let elements: [u32; 4] = bytemuck::cast(mat);
+        assert_eq!(elements, [1, 2, 3, 4]);
+        assert_eq!(
+            <Mat<2, 2, u32> as bytemuck::Zeroable>::zeroed(),
+            Mat::default()
+        );
+    }
+}
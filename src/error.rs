@@ -0,0 +1,39 @@
+//! Error types.
+//!
+//! The dimensions of vectors and matrices are checked at compile time, these
+//! errors come from the operations that depend on runtime values: the length
+//! of a slice, or whether a matrix can be inverted.
+
+use core::fmt;
+
+/// An error returned when the shape of some data doesn't fit an operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ShapeError {
+    /// A sequence doesn't have the number of elements of the vector or matrix
+    /// built from it.
+    LengthMismatch {
+        /// The number of elements needed.
+        expected: usize,
+        /// The number of elements given.
+        found: usize,
+    },
+    /// A square matrix of the given size is singular, so it can't be inverted
+    /// and its linear systems have no unique solution.
+    Singular {
+        /// The number of rows and columns of the matrix.
+        size: usize,
+    },
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapeError::LengthMismatch { expected, found } => {
+                write!(f, "expected {expected} elements, found {found}")
+            }
+            ShapeError::Singular { size } => write!(f, "the {size}x{size} matrix is singular"),
+        }
+    }
+}
+
+impl core::error::Error for ShapeError {}
@@ -0,0 +1,120 @@
+//! Conversions with the `glam` crate, enabled by the `glam` feature.
+//!
+//! `Vec2`, `Vec3` and `Vec4` of `f32` convert to and from `glam::Vec2`, `Vec3`
+//! and `Vec4`, and those of `f64` to and from `DVec2`, `DVec3` and `DVec4`. The
+//! square matrices `Mat<N, N, T>` with `N` in 2, 3 and 4 convert to and from
+//! `Mat2`, `Mat3` and `Mat4`, or `DMat2`, `DMat3` and `DMat4`.
+//!
+//! The conversions keep the mathematical matrix: element `(i, j)` of a `Mat`
+//! is row `i` and column `j` of the glam matrix, which is `m.col(j)[i]` in glam.
+//! Both libraries use column vectors, so a translation stored in the last column
+//! of a `Mat<4, 4, T>` is the `w_axis` of a `glam::Mat4`, and the converted
+//! matrices transform points the same way. Only the storage differs: `Mat` is
+//! stored by rows and glam by columns.
+
+use crate::matrix::Mat;
+use crate::vector::Vec;
+
+macro_rules! impl_glam_vec {
+    ($($dim:literal, $t:ty => $glam:ident),*) => {
+        $(
+            impl From<glam::$glam> for Vec<$dim, $t> {
+                fn from(v: glam::$glam) -> Self {
+                    Vec(v.into())
+                }
+            }
+
+            impl From<Vec<$dim, $t>> for glam::$glam {
+                fn from(v: Vec<$dim, $t>) -> Self {
+                    v.0.into()
+                }
+            }
+        )*
+    };
+}
+
+impl_glam_vec!(
+    2, f32 => Vec2,
+    3, f32 => Vec3,
+    4, f32 => Vec4,
+    2, f64 => DVec2,
+    3, f64 => DVec3,
+    4, f64 => DVec4
+);
+
+macro_rules! impl_glam_mat {
+    ($($dim:literal, $t:ty => $glam:ident),*) => {
+        $(
+            impl From<glam::$glam> for Mat<$dim, $dim, $t> {
+                fn from(m: glam::$glam) -> Self {
+                    // the columns of the transpose are the rows
+                    Mat::from(m.transpose().to_cols_array_2d())
+                }
+            }
+
+            impl From<Mat<$dim, $dim, $t>> for glam::$glam {
+                fn from(m: Mat<$dim, $dim, $t>) -> Self {
+                    glam::$glam::from_cols_array_2d(&m.into()).transpose()
+                }
+            }
+        )*
+    };
+}
+
+impl_glam_mat!(
+    2, f32 => Mat2,
+    3, f32 => Mat3,
+    4, f32 => Mat4,
+    2, f64 => DMat2,
+    3, f64 => DMat3,
+    4, f64 => DMat4
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Mat;
+    use crate::vector::{Vec2, Vec3, Vec4};
+
+    #[test]
+    fn test_vec_round_trip() {
+        let v = Vec3::new(1.0f32, 2.0, 3.0);
+        let g: glam::Vec3 = v.into();
+        assert_eq!(g, glam::Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vec3::from(g), v);
+
+        let v = Vec2::new(1.0f32, 2.0);
+        assert_eq!(Vec2::from(glam::Vec2::from(v)), v);
+        let v = Vec4::new(1.0f32, 2.0, 3.0, 4.0);
+        assert_eq!(Vec4::from(glam::Vec4::from(v)), v);
+        let v = Vec3::new(1.0f64, 2.0, 3.0);
+        assert_eq!(glam::DVec3::from(v), glam::DVec3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vec3::from(glam::DVec3::from(v)), v);
+    }
+
+    #[test]
+    fn test_mat_round_trip() {
+        let mat = Mat::from([[1.0f32, 2.0], [3.0, 4.0]]);
+        let g = glam::Mat2::from(mat);
+        assert_eq!(g.row(0), glam::Vec2::new(1.0, 2.0));
+        assert_eq!(g.col(0), glam::Vec2::new(1.0, 3.0));
+        assert_eq!(Mat::from(g), mat);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_transform_round_trip() {
+        let mat = Mat::translation(Vec3::new(5.0f32, 6.0, 7.0));
+        let g: glam::Mat4 = mat.into();
+        assert_eq!(
+            g,
+            glam::Mat4::from_translation(glam::Vec3::new(5.0, 6.0, 7.0))
+        );
+        assert_eq!(Mat::from(g), mat);
+
+        let mat = Mat::<3, 3, f64>::rotation_z(0.5) * 2.0;
+        let g: glam::DMat3 = mat.into();
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(Vec3::from(g * glam::DVec3::from(v)), mat * v);
+        assert_eq!(Mat::from(g), mat);
+    }
+}
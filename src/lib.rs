@@ -1,5 +1,23 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "simd", feature(portable_simd, min_specialization))]
+
+#[cfg(feature = "approx")]
+mod approx_impl;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impl;
+pub mod error;
+#[cfg(feature = "glam")]
+mod glam_impl;
 mod macros;
 pub mod matrix;
+#[cfg(feature = "mint")]
+mod mint_impl;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl;
 // TODO: docs examples on ops
 pub mod ops;
+pub mod quaternion;
+pub mod scalar;
+#[cfg(feature = "serde")]
+mod serde_impl;
 pub mod vector;
@@ -1,44 +1,279 @@
 /// This macro implement the version of "&U for T", "U for &T" and "&U for &T" for binary operators
+///
+/// The referenced operands are copied, `forward_ref_elementwise` avoids it for
+/// the element-wise operators of matrices.
 macro_rules! forward_ref_binop {
     (impl$(<$($generic:ident),* $(;$(const $const_name:ident : $const_ty:ty),*)?>)? $trait:ident<$u:ty>, $method:ident for $t:ty $(where $($tt:tt)*)?) => {
-        impl<$($($generic),*$(,$(const $const_name: $const_ty),*)?)?> $trait<$u> for &$t
+        impl<$($($generic,)* $($(const $const_name: $const_ty),*)?)?> $trait<$u> for &$t
         $(where $($tt)*)?
         {
             type Output = <$t as $trait<$u>>::Output;
 
             #[inline]
             fn $method(self, other: $u) -> Self::Output {
-                // TODO: avoid copy (due to big copy of a big vector for example)
                 $trait::$method(*self, other)
             }
         }
 
-        impl<$($($generic),*$(,$(const $const_name: $const_ty),*)?)?> $trait<&$u> for $t
+        impl<$($($generic,)* $($(const $const_name: $const_ty),*)?)?> $trait<&$u> for $t
         $(where $($tt)*)?
         {
             type Output = <$t as $trait<$u>>::Output;
 
             #[inline]
             fn $method(self, other: &$u) -> <$t as $trait<$u>>::Output {
-                // TODO: avoid copy (due to big copy of a big vector for example)
                 $trait::$method(self, *other)
             }
         }
 
-        impl<$($($generic),*$(,$(const $const_name: $const_ty),*)?)?> $trait<&$u> for &$t
+        impl<$($($generic,)* $($(const $const_name: $const_ty),*)?)?> $trait<&$u> for &$t
         $(where $($tt)*)?
         {
             type Output = <$t as $trait<$u>>::Output;
 
             #[inline]
             fn $method(self, other: &$u) -> <$t as $trait<$u>>::Output {
-                // TODO: avoid copy (due to big copy of a big vector for example)
                 $trait::$method(*self, *other)
             }
         }
     };
 }
 
+/// This macro implement the versions "&U for T", "U for &T" and "&U for &T" of an element-wise
+/// operator on matrices.
+///
+/// Unlike `forward_ref_binop`, the operands aren't copied: the operator is
+/// applied to references to the elements, so big matrices aren't copied and
+/// the element type doesn't need to be `Copy`. `Mat` and the operator trait
+/// must be in scope where it's used.
+macro_rules! forward_ref_elementwise {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<T, U, R, const M: usize, const N: usize> $trait<Mat<M, N, U>> for &Mat<M, N, T>
+        where
+            for<'a> &'a T: $trait<U, Output = R>,
+        {
+            type Output = Mat<M, N, R>;
+
+            #[inline]
+            fn $method(self, rhs: Mat<M, N, U>) -> Self::Output {
+                let mut i = 0;
+                Mat(rhs.0.map(|b| {
+                    let row = b.combine_ref(&self.0[i], |b, a| a $op b);
+                    i += 1;
+                    row
+                }))
+            }
+        }
+
+        impl<T, U, R, const M: usize, const N: usize> $trait<&Mat<M, N, U>> for Mat<M, N, T>
+        where
+            T: for<'a> $trait<&'a U, Output = R>,
+        {
+            type Output = Mat<M, N, R>;
+
+            #[inline]
+            fn $method(self, rhs: &Mat<M, N, U>) -> Self::Output {
+                let mut i = 0;
+                Mat(self.0.map(|a| {
+                    let row = a.combine_ref(&rhs.0[i], |a, b| a $op b);
+                    i += 1;
+                    row
+                }))
+            }
+        }
+
+        impl<T, U, R, const M: usize, const N: usize> $trait<&Mat<M, N, U>> for &Mat<M, N, T>
+        where
+            for<'a, 'b> &'a T: $trait<&'b U, Output = R>,
+        {
+            type Output = Mat<M, N, R>;
+
+            #[inline]
+            fn $method(self, rhs: &Mat<M, N, U>) -> Self::Output {
+                Mat(core::array::from_fn(|i| {
+                    self.0[i].combine_both_ref(&rhs.0[i], |a, b| a $op b)
+                }))
+            }
+        }
+    };
+}
+
+/// This macro implement the version "&T" of unary operators, delegating to the owned "T"
+macro_rules! forward_ref_unop {
+    (impl$(<$($generic:ident),* $(;$(const $const_name:ident : $const_ty:ty),*)?>)? $trait:ident, $method:ident for $t:ty $(where $($tt:tt)*)?) => {
+        impl<$($($generic,)* $($(const $const_name: $const_ty),*)?)?> $trait for &$t
+        $(where $($tt)*)?
+        {
+            type Output = <$t as $trait>::Output;
+
+            #[inline]
+            fn $method(self) -> Self::Output {
+                $trait::$method(*self)
+            }
+        }
+    };
+}
+
+/// This macro implement the getter, setter and `with_*` copy of each named
+/// component. The example vector and the expected tuples after setting a
+/// component to 9 are used in the documentation of `with_*`
+macro_rules! accessors {
+    ($vec:ident $example:tt; $($get:ident, $set:ident, $with:ident => $index:literal: $expected:expr),* $(,)?) => {
+        $(
+            #[doc = concat!("Get the `", stringify!($get), "` component of the vector.")]
+            ///
+            /// Unlike the field of the same name, reached through the window
+            /// `Deref`, this copies the component out and doesn't borrow the vector.
+            #[inline]
+            pub fn $get(&self) -> T
+            where
+                T: Copy,
+            {
+                self.0[$index]
+            }
+
+            #[doc = concat!("Set the `", stringify!($get), "` component of the vector.")]
+            #[inline]
+            pub fn $set(&mut self, value: T) {
+                self.0[$index] = value;
+            }
+
+            #[doc = concat!("Get a copy of the vector with the `", stringify!($get), "` component replaced.")]
+            ///
+            /// # Example
+            /// ```
+            #[doc = concat!("use isochro::vector::", stringify!($vec), ";")]
+            #[doc = concat!(
+                "assert_eq!(", stringify!($vec), "::new", stringify!($example), ".",
+                stringify!($with), "(9), ", stringify!($expected), ");"
+            )]
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn $with(mut self, value: T) -> Self {
+                self.0[$index] = value;
+                self
+            }
+        )*
+    };
+}
+
+/// This macro implement swizzle accessors, each one copying the listed components in a new vector
+macro_rules! swizzle {
+    ($($name:ident => $out:ident[$($index:literal),+]),* $(,)?) => {
+        $(
+            #[doc = concat!("Get the `", stringify!($name), "` swizzle of the vector.")]
+            #[inline]
+            pub fn $name(&self) -> $out<T>
+            where
+                T: Copy,
+            {
+                crate::vector::Vec([$(self.0[$index]),+])
+            }
+        )*
+    };
+}
+
+/// This macro implement `Deref` and `DerefMut` from a vector to its [`Window`](crate::vector::Window)
+macro_rules! window_deref {
+    ($window:ident<$dim:literal>) => {
+        impl<T> core::ops::Deref for crate::vector::Vec<$dim, T> {
+            type Target = $window<T>;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                self.window()
+            }
+        }
+
+        impl<T> core::ops::DerefMut for crate::vector::Vec<$dim, T> {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                self.window_mut()
+            }
+        }
+    };
+}
+
+/// Declare a [`Window`](crate::vector::Window) naming the components of a vector.
+///
+/// `struct Name<D> { a, b, ... }` declares a `#[repr(C)]` struct `Name<T>` with
+/// one public field of type `T` per listed name, and implement `Window<D, T>`
+/// for it. The number of fields must be `D`, which is checked at compile time
+/// when the window is used. The vector is then viewed through the window with
+/// [`Vec::window`](crate::vector::Vec::window) and
+/// [`Vec::window_mut`](crate::vector::Vec::window_mut).
+///
+/// # Example
+/// ```
+/// use isochro::vector::Vec;
+/// isochro::window! {
+///     /// A 6-dimensional vector of the position and velocity of a body.
+///     #[derive(Debug)]
+///     pub struct State<6> { px, py, pz, vx, vy, vz }
+/// }
+///
+/// let state = Vec([1, 2, 3, 4, 5, 6]);
+/// let fields: &State<i32> = state.window();
+/// assert_eq!((fields.pz, fields.vx), (3, 4));
+/// ```
+///
+/// A window with the wrong number of fields doesn't compile once used:
+/// ```compile_fail
+/// use isochro::vector::Vec;
+/// isochro::window! {
+///     pub struct Pair<3> { a, b }
+/// }
+///
+/// let pair: &Pair<i32> = Vec([1, 2, 3]).window();
+/// ```
+#[macro_export]
+macro_rules! window {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident<$dim:literal> { $($field:ident),+ $(,)? }) => {
+        $(#[$attr])*
+        #[repr(C)]
+        $vis struct $name<T> {
+            $(pub $field: T),+
+        }
+
+        // SAFETY: `#[repr(C)]` with only fields of type `T`, the count is checked below
+        unsafe impl<T> $crate::vector::Window<$dim, T> for $name<T> {
+            const LAYOUT_CHECK: () = {
+                let mut _index = 0;
+                $(
+                    assert!(
+                        ::core::mem::offset_of!(Self, $field) == _index * ::core::mem::size_of::<T>()
+                    );
+                    _index += 1;
+                )+
+                assert!(_index == $dim, "the window must have one field per component");
+            };
+        }
+    };
+}
+
+/// This macro implement swizzle setters, each one writing the components of a
+/// smaller vector at the listed indices
+macro_rules! swizzle_set {
+    ($($name:ident => $in:ident[$($index:literal),+]),* $(,)?) => {
+        $(
+            #[doc = concat!("Set the components written by `", stringify!($name), "` from a smaller vector, leaving the others untouched.")]
+            #[inline]
+            pub fn $name(&mut self, value: $in<T>) {
+                for (index, x) in [$($index),+].into_iter().zip(value.0) {
+                    self.0[index] = x;
+                }
+            }
+        )*
+    };
+}
+
 // This trick allow the usage of the macros exported without the inconvence of
 // the #[macro_export] that is more like an pub
+pub(crate) use accessors;
 pub(crate) use forward_ref_binop;
+pub(crate) use forward_ref_elementwise;
+pub(crate) use forward_ref_unop;
+pub(crate) use swizzle;
+pub(crate) use swizzle_set;
+pub(crate) use window_deref;
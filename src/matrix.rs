@@ -4,15 +4,25 @@
 //! The dimensionality of the matrix is specified as a type parameter.
 //! This allows the compiler to catch errors where matrices of different
 //! sizes are used incorrectly.
+//!
+//! Vectors are treated as columns: a matrix transforms a vector with `m * v`,
+//! and `a * b` applies `b` first, then `a`.
 
-use std::iter::zip;
-use std::ops::{Add, Index, IndexMut};
+mod linalg;
+mod transform;
 
-use crate::macros::forward_ref_binop;
+use core::iter::zip;
+use core::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+use crate::error::ShapeError;
+use crate::macros::{forward_ref_binop, forward_ref_elementwise, forward_ref_unop};
+use crate::ops::DotProduct;
+use crate::scalar::{One, Zero};
 use crate::vector::Vec;
 
 /// A generic matrix type with compile-time dimensionality.
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)]
 pub struct Mat<const ROW: usize, const COL: usize, T>(pub [Vec<COL, T>; ROW]);
 
 impl<T, const M: usize, const N: usize> Index<usize> for Mat<M, N, T> {
@@ -47,36 +57,959 @@ impl<T, const M: usize, const N: usize> IndexMut<(usize, usize)> for Mat<M, N, T
     }
 }
 
+impl<T: Default, const M: usize, const N: usize> Default for Mat<M, N, T> {
+    /// Create a matrix with every element set to its default value.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// let m = Mat::<2, 3, i32>::default();
+    /// assert_eq!(m[(1, 2)], 0);
+    /// ```
+    fn default() -> Self {
+        Self(core::array::from_fn(|_| Vec::default()))
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<[[T; N]; M]> for Mat<M, N, T> {
+    /// Create a matrix from an array of rows.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// let m = Mat::from([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(m[0], (1, 2, 3));
+    /// assert_eq!(m[(1, 0)], 4);
+    /// ```
+    fn from(rows: [[T; N]; M]) -> Self {
+        Mat(rows.map(Vec))
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<Mat<M, N, T>> for [[T; N]; M] {
+    /// Convert a matrix into an array of rows.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// let m = Mat::<2, 2, i32>::identity();
+    /// let rows: [[i32; 2]; 2] = m.into();
+    /// assert_eq!(rows, [[1, 0], [0, 1]]);
+    /// ```
+    fn from(mat: Mat<M, N, T>) -> Self {
+        mat.0.map(|row| row.0)
+    }
+}
+
+impl<T: Copy, const M: usize, const N: usize> TryFrom<&[T]> for Mat<M, N, T> {
+    type Error = ShapeError;
+
+    /// Create a matrix from a slice of its elements in row-major order, failing
+    /// if the slice doesn't have `M * N` elements.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::error::ShapeError;
+    /// use isochro::matrix::Mat;
+    /// let data = [1, 2, 3, 4, 5, 6];
+    /// let m = Mat::<2, 3, _>::try_from(&data[..]);
+    /// assert_eq!(m, Ok(Mat::from([[1, 2, 3], [4, 5, 6]])));
+    /// let m = Mat::<2, 2, _>::try_from(&data[..]);
+    /// assert_eq!(m, Err(ShapeError::LengthMismatch { expected: 4, found: 6 }));
+    /// ```
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        if slice.len() != M * N {
+            return Err(ShapeError::LengthMismatch {
+                expected: M * N,
+                found: slice.len(),
+            });
+        }
+        Ok(Mat(core::array::from_fn(|i| {
+            Vec(core::array::from_fn(|j| slice[i * N + j]))
+        })))
+    }
+}
+
+impl<T, const M: usize, const N: usize> Mat<M, N, T> {
+    /// The number of rows of the matrix.
+    pub const ROWS: usize = M;
+    /// The number of columns of the matrix.
+    pub const COLS: usize = N;
+
+    /// Check if the matrix has as many rows as columns.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// assert!(Mat::<3, 3, f32>::is_square());
+    /// assert!(!Mat::<2, 3, f32>::is_square());
+    /// assert_eq!((Mat::<2, 3, f32>::ROWS, Mat::<2, 3, f32>::COLS), (2, 3));
+    /// ```
+    pub const fn is_square() -> bool {
+        M == N
+    }
+
+    /// Create a matrix from its rows.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec3;
+    /// let m = Mat::from_rows([Vec3::new(1, 2, 3), Vec3::new(4, 5, 6)]);
+    /// assert_eq!(m[0], (1, 2, 3));
+    /// assert_eq!(m[(1, 0)], 4);
+    /// ```
+    pub fn from_rows(rows: [Vec<N, T>; M]) -> Self {
+        Mat(rows)
+    }
+
+    /// Create a matrix from its columns.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec2;
+    /// let m = Mat::from_cols([Vec2::new(1, 2), Vec2::new(3, 4), Vec2::new(5, 6)]);
+    /// assert_eq!(m[0], (1, 3, 5));
+    /// assert_eq!(m[1], (2, 4, 6));
+    /// ```
+    pub fn from_cols(cols: [Vec<M, T>; N]) -> Self {
+        let mut cols = cols.map(Vec::into_iter);
+        Mat(core::array::from_fn(|_| {
+            Vec(core::array::from_fn(|j| {
+                cols[j].next().expect("every column has M elements")
+            }))
+        }))
+    }
+
+    /// Get a copy of the row at the given index.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec2;
+    /// let m = Mat([Vec2::new(1, 2), Vec2::new(3, 4)]);
+    /// assert_eq!(m.row(0), (1, 2));
+    /// ```
+    pub fn row(&self, i: usize) -> Vec<N, T>
+    where
+        T: Copy,
+    {
+        self.0[i]
+    }
+
+    /// Get a copy of the column at the given index.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec2;
+    /// let m = Mat([Vec2::new(1, 2), Vec2::new(3, 4)]);
+    /// assert_eq!(m.col(1), (2, 4));
+    /// ```
+    pub fn col(&self, j: usize) -> Vec<M, T>
+    where
+        T: Copy,
+    {
+        Vec(self.0.map(|row| row.0[j]))
+    }
+
+    /// Reduce every entry of the matrix into a single value, starting from `init`.
+    ///
+    /// Entries are visited in row-major order: the whole first row from left
+    /// to right, then the second row, and so on.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec2;
+    /// let m = Mat([Vec2::new(1, 2), Vec2::new(3, 4)]);
+    /// let sum = m.fold(0, |acc, x| acc + x);
+    /// assert_eq!(sum, 10);
+    /// ```
+    pub fn fold<B>(&self, init: B, f: impl FnMut(B, &T) -> B) -> B {
+        self.0.iter().flat_map(|row| row.0.iter()).fold(init, f)
+    }
+
+    /// View all the elements of the matrix as a slice, in row-major order.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec3;
+    /// let m = Mat([Vec3::new(1, 2, 3), Vec3::new(4, 5, 6)]);
+    /// assert_eq!(m.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: `Vec<N, T>` is `repr(transparent)` over `[T; N]`, so `[Vec<N, T>; M]`
+        // has the layout of `[[T; N]; M]`, which is `M * N` contiguous elements.
+        unsafe { core::slice::from_raw_parts(self.0.as_ptr().cast::<T>(), M * N) }
+    }
+
+    /// View all the elements of the matrix as a mutable slice, in row-major order.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec2;
+    /// let mut m = Mat([Vec2::new(1, 2), Vec2::new(3, 4)]);
+    /// m.as_mut_slice()[2] = 7;
+    /// assert_eq!(m[(1, 0)], 7);
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: see `as_slice`, the exclusive borrow of `self` covers every element.
+        unsafe { core::slice::from_raw_parts_mut(self.0.as_mut_ptr().cast::<T>(), M * N) }
+    }
+
+    /// Apply a function to every element of the matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// let m = Mat::from([[1, 2], [3, 4]]);
+    /// let f: Mat<2, 2, f64> = m.map(f64::from);
+    /// assert_eq!(f, Mat::from([[1.0, 2.0], [3.0, 4.0]]));
+    /// ```
+    pub fn map<R>(self, f: impl Fn(T) -> R) -> Mat<M, N, R> {
+        Mat(self.0.map(|row| row.map(&f)))
+    }
+
+    /// Combine the elements of two matrices of the same size with a function.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// let a = Mat::from([[250_u8, 2], [3, 4]]);
+    /// let b = Mat::from([[10_u8, 20], [30, 40]]);
+    /// assert_eq!(a.zip_map(b, u8::saturating_add), Mat::from([[255, 22], [33, 44]]));
+    /// ```
+    pub fn zip_map<U, R>(self, other: Mat<M, N, U>, f: impl Fn(T, U) -> R) -> Mat<M, N, R> {
+        let mut iter = zip(self.0, other.0).map(|(a, b)| a.combine(b, &f));
+        Mat(core::array::from_fn(|_| {
+            iter.next().expect("the matrices have M rows")
+        }))
+    }
+
+    /// Iterate over all the elements of the matrix, in row-major order.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// let m = Mat::from([[1, 2, 3], [4, 5, 6]]);
+    /// let max = m.iter_elements().max();
+    /// assert_eq!(max, Some(&6));
+    /// ```
+    pub fn iter_elements(&self) -> core::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Iterate over the rows of the matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec3;
+    /// let m = Mat::from([[1, 2, 3], [4, 5, 6]]);
+    /// let mut rows = m.iter_rows();
+    /// assert_eq!(rows.next(), Some(&Vec3::new(1, 2, 3)));
+    /// assert_eq!(rows.next(), Some(&Vec3::new(4, 5, 6)));
+    /// assert_eq!(rows.next(), None);
+    /// ```
+    pub fn iter_rows(&self) -> core::slice::Iter<'_, Vec<N, T>> {
+        self.0.iter()
+    }
+
+    /// Iterate over the columns of the matrix, each column being a vector of
+    /// references to its elements.
+    ///
+    /// The matrix is stored by rows, so the columns are gathered on the fly.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// let m = Mat::from([[1, 2, 3], [4, 5, 6]]);
+    /// let sums: Vec<i32> = m.iter_cols().map(|col| col.0.into_iter().sum()).collect();
+    /// assert_eq!(sums, [5, 7, 9]);
+    /// ```
+    pub fn iter_cols(&self) -> impl ExactSizeIterator<Item = Vec<M, &T>> + DoubleEndedIterator {
+        (0..N).map(move |j| Vec(core::array::from_fn(|i| &self.0[i].0[j])))
+    }
+
+    /// Swap the rows `i` and `j`, an elementary row operation.
+    ///
+    /// # Panics
+    /// Panics if `i` or `j` is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// let mut m = Mat::from([[1, 2], [3, 4]]);
+    /// m.swap_rows(0, 1);
+    /// assert_eq!(m, Mat::from([[3, 4], [1, 2]]));
+    /// ```
+    pub fn swap_rows(&mut self, i: usize, j: usize) {
+        self.0.swap(i, j);
+    }
+
+    /// Multiply the row `i` by `factor`, an elementary row operation when the
+    /// factor isn't zero.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// let mut m = Mat::from([[1, 2], [3, 4]]);
+    /// m.scale_row(1, -2);
+    /// assert_eq!(m, Mat::from([[1, 2], [-6, -8]]));
+    /// ```
+    pub fn scale_row(&mut self, i: usize, factor: T)
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        self.0[i].combine_assign_scalar(factor, |x, factor| *x = *x * factor);
+    }
+
+    /// Add the row `src` multiplied by `factor` to the row `dst`, an elementary
+    /// row operation when the rows are different.
+    ///
+    /// # Panics
+    /// Panics if `dst` or `src` is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// let mut m = Mat::from([[1, 2], [3, 4]]);
+    /// // eliminate the first element of the second row
+    /// m.add_scaled_row(1, 0, -3);
+    /// assert_eq!(m, Mat::from([[1, 2], [0, -2]]));
+    /// ```
+    pub fn add_scaled_row(&mut self, dst: usize, src: usize, factor: T)
+    where
+        T: Mul<Output = T> + Add<Output = T> + Copy,
+    {
+        let src = self.0[src];
+        self.0[dst].combine_assign(src, |x, s| *x = *x + s * factor);
+    }
+}
+
+impl<T, const M: usize, const N: usize> IntoIterator for Mat<M, N, T> {
+    type Item = Vec<N, T>;
+    type IntoIter = core::array::IntoIter<Vec<N, T>, M>;
+
+    /// Iterate over the rows of the matrix.
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize> IntoIterator for &'a Mat<M, N, T> {
+    type Item = &'a Vec<N, T>;
+    type IntoIter = core::slice::Iter<'a, Vec<N, T>>;
+
+    /// Iterate over references to the rows of the matrix.
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize> IntoIterator for &'a mut Mat<M, N, T> {
+    type Item = &'a mut Vec<N, T>;
+    type IntoIter = core::slice::IterMut<'a, Vec<N, T>>;
+
+    /// Iterate over mutable references to the rows of the matrix.
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<T, const N: usize> Mat<N, N, T> {
+    /// Create the identity matrix, with ones on the diagonal and zeros elsewhere.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// let m = Mat::<3, 3, f32>::identity();
+    /// assert_eq!(m[(1, 1)], 1.0);
+    /// assert_eq!(m[(0, 1)], 0.0);
+    /// ```
+    pub fn identity() -> Self
+    where
+        T: Zero + One,
+    {
+        Mat(core::array::from_fn(|i| {
+            Vec(core::array::from_fn(|j| {
+                if i == j { T::one() } else { T::zero() }
+            }))
+        }))
+    }
+
+    /// Create a diagonal matrix, with the given diagonal and zeros elsewhere.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec3;
+    /// let m = Mat::from_diagonal(Vec3::new(2, 3, 4));
+    /// assert_eq!(m[(1, 1)], 3);
+    /// assert_eq!(m[(1, 2)], 0);
+    /// assert_eq!(Mat::from_diagonal(Vec3::splat(1)), Mat::identity());
+    /// ```
+    pub fn from_diagonal(diagonal: Vec<N, T>) -> Self
+    where
+        T: Zero,
+    {
+        let mut diagonal = diagonal.into_iter();
+        Mat(core::array::from_fn(|i| {
+            Vec(core::array::from_fn(|j| {
+                if i == j {
+                    diagonal.next().expect("the diagonal has N elements")
+                } else {
+                    T::zero()
+                }
+            }))
+        }))
+    }
+
+    /// Get a copy of the diagonal of the matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec3;
+    /// let m = Mat::<3, 3, i32>::identity();
+    /// assert_eq!(m.diagonal(), Vec3::splat(1));
+    /// ```
+    pub fn diagonal(&self) -> Vec<N, T>
+    where
+        T: Copy,
+    {
+        Vec(core::array::from_fn(|i| self[(i, i)]))
+    }
+
+    /// Sum the elements of the diagonal.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// assert_eq!(Mat::<3, 3, i32>::identity().trace(), 3);
+    /// ```
+    pub fn trace(&self) -> T
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        self.diagonal().sum()
+    }
+
+    /// Raise the matrix to the power `n`, by repeated squaring. The power `0`
+    /// is the identity matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// // the Fibonacci numbers
+    /// let m = Mat::from([[1, 1], [1, 0]]);
+    /// assert_eq!(m.powi(10), Mat::from([[89, 55], [55, 34]]));
+    /// assert_eq!(m.powi(0), Mat::identity());
+    /// ```
+    pub fn powi(self, n: u32) -> Self
+    where
+        T: Mul<Output = T> + Add<Output = T> + Zero + One + Copy,
+    {
+        let mut result = Self::identity();
+        let mut base = self;
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result * base;
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base * base;
+            }
+        }
+        result
+    }
+}
+
+impl<T, const D: usize> Vec<D, T> {
+    /// Turn the vector into a matrix with a single row.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec3;
+    /// let row: Mat<1, 3, i32> = Vec3::new(1, 2, 3).as_row_matrix();
+    /// assert_eq!(row[(0, 2)], 3);
+    /// ```
+    pub fn as_row_matrix(self) -> Mat<1, D, T> {
+        Mat([self])
+    }
+
+    /// Turn the vector into a matrix with a single column.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec3;
+    /// let col: Mat<3, 1, i32> = Vec3::new(1, 2, 3).as_col_matrix();
+    /// assert_eq!(col[(2, 0)], 3);
+    ///
+    /// // a column times a row is the outer product
+    /// let outer = col * Vec3::new(1, 10, 100).as_row_matrix();
+    /// assert_eq!(outer[(1, 2)], 200);
+    /// ```
+    pub fn as_col_matrix(self) -> Mat<D, 1, T> {
+        Mat(self.0.map(|x| Vec([x])))
+    }
+
+    /// Calculate the outer product of two vectors, the element `(i, j)` of the
+    /// result being `self[i] * other[j]`.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::{Vec2, Vec3};
+    /// let a = Vec2::new(1, 2);
+    /// let b = Vec3::new(3, 4, 5);
+    /// let m = a.outer(b);
+    /// assert_eq!(m, Mat([Vec3::new(3, 4, 5), Vec3::new(6, 8, 10)]));
+    /// ```
+    pub fn outer<R, const N: usize>(self, other: Vec<N, T>) -> Mat<D, N, R>
+    where
+        T: Mul<Output = R> + Copy,
+    {
+        Mat(self.0.map(|a| other.map(|b| a * b)))
+    }
+}
+
 impl<T, U, R, const M: usize, const N: usize> Add<Mat<M, N, U>> for Mat<M, N, T>
 where
     T: Add<U, Output = R>,
 {
     type Output = Mat<M, N, R>;
 
-    /// Add two vectors together.
+    /// Add two matrices together.
     /// # Example
     /// ```
+    /// use isochro::matrix::Mat;
     /// use isochro::vector::Vec2;
-    /// let a = Vec2::new(1, 2);
-    /// let b = Vec2::new(3, 4);
+    /// let a = Mat([Vec2::new(1, 2), Vec2::new(3, 4)]);
+    /// let b = Mat([Vec2::new(5, 6), Vec2::new(7, 8)]);
     /// let c = a + b;
-    /// assert_eq!(c.x, 4);
-    /// assert_eq!(c.y, 6);
+    /// assert_eq!(c[0], (6, 8));
+    /// assert_eq!(c[1], (10, 12));
     /// ```
     fn add(self, rhs: Mat<M, N, U>) -> Self::Output {
         let a = self.0.into_iter();
         let b = rhs.0.into_iter();
         let mut iter = zip(a, b).map(|(a, b)| a + b);
 
-        Mat(std::array::from_fn(|_| unsafe {
-            iter.next().unwrap_unchecked()
+        Mat(core::array::from_fn(|_| {
+            iter.next().expect("the matrices have M rows")
         }))
     }
 }
 
+forward_ref_elementwise!(Add, add, +);
+
+impl<T, U, const M: usize, const N: usize> AddAssign<Mat<M, N, U>> for Mat<M, N, T>
+where
+    T: AddAssign<U>,
+{
+    /// Add a matrix to another.
+    fn add_assign(&mut self, rhs: Mat<M, N, U>) {
+        for (a, b) in zip(&mut self.0, rhs.0) {
+            *a += b;
+        }
+    }
+}
+
+impl<T, U, const M: usize, const N: usize> AddAssign<&Mat<M, N, U>> for Mat<M, N, T>
+where
+    T: for<'a> AddAssign<&'a U>,
+{
+    /// Add a matrix to another.
+    fn add_assign(&mut self, rhs: &Mat<M, N, U>) {
+        for (a, b) in zip(&mut self.0, &rhs.0) {
+            *a += b;
+        }
+    }
+}
+
+impl<T, U, R, const M: usize, const N: usize> Sub<Mat<M, N, U>> for Mat<M, N, T>
+where
+    T: Sub<U, Output = R>,
+{
+    type Output = Mat<M, N, R>;
+
+    /// Subtract one matrix from another.
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec2;
+    /// let a = Mat([Vec2::new(1, 2), Vec2::new(3, 4)]);
+    /// let b = Mat([Vec2::new(5, 6), Vec2::new(7, 8)]);
+    /// let c = a - b;
+    /// assert_eq!(c[0], (-4, -4));
+    /// assert_eq!(c[1], (-4, -4));
+    /// ```
+    fn sub(self, rhs: Mat<M, N, U>) -> Self::Output {
+        let a = self.0.into_iter();
+        let b = rhs.0.into_iter();
+        let mut iter = zip(a, b).map(|(a, b)| a - b);
+
+        Mat(core::array::from_fn(|_| {
+            iter.next().expect("the matrices have M rows")
+        }))
+    }
+}
+
+forward_ref_elementwise!(Sub, sub, -);
+
+impl<T, U, const M: usize, const N: usize> SubAssign<Mat<M, N, U>> for Mat<M, N, T>
+where
+    T: SubAssign<U>,
+{
+    /// Subtract a matrix from another.
+    fn sub_assign(&mut self, rhs: Mat<M, N, U>) {
+        for (a, b) in zip(&mut self.0, rhs.0) {
+            *a -= b;
+        }
+    }
+}
+
+impl<T, U, const M: usize, const N: usize> SubAssign<&Mat<M, N, U>> for Mat<M, N, T>
+where
+    T: for<'a> SubAssign<&'a U>,
+{
+    /// Subtract a matrix from another.
+    fn sub_assign(&mut self, rhs: &Mat<M, N, U>) {
+        for (a, b) in zip(&mut self.0, &rhs.0) {
+            *a -= b;
+        }
+    }
+}
+
+impl<T, R, const M: usize, const N: usize> Mul<T> for Mat<M, N, T>
+where
+    T: Mul<T, Output = R> + Clone,
+{
+    type Output = Mat<M, N, R>;
+
+    /// Multiply a matrix by a scalar.
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec2;
+    /// let a = Mat([Vec2::new(1, 2), Vec2::new(3, 4)]);
+    /// let c = a * 2;
+    /// assert_eq!(c[0], (2, 4));
+    /// assert_eq!(c[1], (6, 8));
+    /// ```
+    fn mul(self, rhs: T) -> Self::Output {
+        Mat(self.0.map(|row| row * rhs.clone()))
+    }
+}
+
 forward_ref_binop! {
-    impl<T, U, R; const M: usize, const N: usize> Add<Mat<M, N, U>>, add for Mat<M, N, T>
+    impl<T, R; const M: usize, const N: usize> Mul<T>, mul for Mat<M, N, T>
     where
-        T: Add<U, Output = R> + Copy,
-        U: Copy,
+        T: Mul<T, Output = R> + Copy,
+}
+
+impl<T, R, const M: usize, const N: usize, const P: usize> Mul<Mat<N, P, T>> for Mat<M, N, T>
+where
+    T: Mul<T, Output = R> + Copy,
+    R: Add<R, Output = R>,
+{
+    type Output = Mat<M, P, R>;
+
+    /// Multiply two matrices, each element of the result being the dot product
+    /// of a row of `self` and a column of `rhs`.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec2;
+    /// let a = Mat([Vec2::new(1, 2), Vec2::new(3, 4)]);
+    /// let b = Mat([Vec2::new(5, 6), Vec2::new(7, 8)]);
+    /// let c = a * b;
+    /// assert_eq!(c, Mat([Vec2::new(19, 22), Vec2::new(43, 50)]));
+    /// ```
+    fn mul(self, rhs: Mat<N, P, T>) -> Self::Output {
+        let cols: [Vec<N, T>; P] = core::array::from_fn(|j| rhs.col(j));
+        Mat(self
+            .0
+            .map(|row| Vec(core::array::from_fn(|j| row.dot(cols[j])))))
+    }
+}
+
+forward_ref_binop! {
+    impl<T, R; const M: usize, const N: usize, const P: usize> Mul<Mat<N, P, T>>, mul for Mat<M, N, T>
+    where
+        T: Mul<T, Output = R> + Copy,
+        R: Add<R, Output = R>,
+}
+
+impl<T, R, const M: usize, const N: usize> Mul<Vec<N, T>> for Mat<M, N, T>
+where
+    T: Mul<T, Output = R> + Copy,
+    R: Add<R, Output = R>,
+{
+    type Output = Vec<M, R>;
+
+    /// Multiply a matrix by a column vector.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec2;
+    /// let m = Mat([Vec2::new(1, 2), Vec2::new(3, 4)]);
+    /// let v = Vec2::new(1, 1);
+    /// assert_eq!(m * v, (3, 7));
+    /// ```
+    fn mul(self, rhs: Vec<N, T>) -> Self::Output {
+        Vec(self.0.map(|row| row.dot(rhs)))
+    }
+}
+
+forward_ref_binop! {
+    impl<T, R; const M: usize, const N: usize> Mul<Vec<N, T>>, mul for Mat<M, N, T>
+    where
+        T: Mul<T, Output = R> + Copy,
+        R: Add<R, Output = R>,
+}
+
+impl<T, R, const M: usize, const N: usize> Neg for Mat<M, N, T>
+where
+    T: Neg<Output = R>,
+{
+    type Output = Mat<M, N, R>;
+
+    /// Negate every element of a matrix.
+    /// # Example
+    /// ```
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec2;
+    /// let a = Mat([Vec2::new(1, -2), Vec2::new(3, -4)]);
+    /// let c = -a;
+    /// assert_eq!(c[0], (-1, 2));
+    /// assert_eq!(c[1], (-3, 4));
+    /// ```
+    fn neg(self) -> Self::Output {
+        Mat(self.0.map(|row| -row))
+    }
+}
+
+forward_ref_unop! {
+    impl<T, R; const M: usize, const N: usize> Neg, neg for Mat<M, N, T>
+    where
+        T: Neg<Output = R> + Copy,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mat(rows: [[i32; 2]; 2]) -> Mat<2, 2, i32> {
+        Mat::from(rows)
+    }
+
+    #[test]
+    fn test_mat_iteration_order() {
+        let mut m = Mat::from([[1, 2, 3], [4, 5, 6]]);
+        let elements: std::vec::Vec<i32> = m.iter_elements().copied().collect();
+        assert_eq!(elements, [1, 2, 3, 4, 5, 6]);
+
+        let cols: std::vec::Vec<Vec<2, i32>> = m.iter_cols().map(|col| col.map(|x| *x)).collect();
+        assert_eq!(cols, [Vec([1, 4]), Vec([2, 5]), Vec([3, 6])]);
+        assert_eq!(m.iter_cols().len(), 3);
+        assert_eq!(m.iter_cols().next_back().map(|col| *col[1]), Some(6));
+
+        for row in &mut m {
+            row[0] = 0;
+        }
+        let rows: std::vec::Vec<Vec<3, i32>> = m.into_iter().collect();
+        assert_eq!(rows, [Vec([0, 2, 3]), Vec([0, 5, 6])]);
+    }
+
+    #[test]
+    fn test_mat_add_sub_ref_forms_without_copy() {
+        #[derive(Debug, PartialEq)]
+        struct Big(i32);
+
+        impl Add<Big> for &Big {
+            type Output = Big;
+            fn add(self, rhs: Big) -> Big {
+                Big(self.0 + rhs.0)
+            }
+        }
+
+        impl Add<&Big> for Big {
+            type Output = Big;
+            fn add(self, rhs: &Big) -> Big {
+                Big(self.0 + rhs.0)
+            }
+        }
+
+        impl Add<&Big> for &Big {
+            type Output = Big;
+            fn add(self, rhs: &Big) -> Big {
+                Big(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub<Big> for &Big {
+            type Output = Big;
+            fn sub(self, rhs: Big) -> Big {
+                Big(self.0 - rhs.0)
+            }
+        }
+
+        impl Sub<&Big> for Big {
+            type Output = Big;
+            fn sub(self, rhs: &Big) -> Big {
+                Big(self.0 - rhs.0)
+            }
+        }
+
+        impl Sub<&Big> for &Big {
+            type Output = Big;
+            fn sub(self, rhs: &Big) -> Big {
+                Big(self.0 - rhs.0)
+            }
+        }
+
+        let big = |m: Mat<2, 2, i32>| Mat(m.0.map(|row| row.map(Big)));
+        let a = mat([[1, 2], [3, 4]]);
+        let b = mat([[5, 6], [7, 8]]);
+        let expected = big(a + b);
+        assert_eq!(&big(a) + &big(b), expected);
+        assert_eq!(big(a) + &big(b), expected);
+        assert_eq!(&big(a) + big(b), expected);
+
+        let expected = big(a - b);
+        assert_eq!(&big(a) - &big(b), expected);
+        assert_eq!(big(a) - &big(b), expected);
+        assert_eq!(&big(a) - big(b), expected);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_mat_sub_ref_forms() {
+        let a = mat([[5, 6], [7, 8]]);
+        let b = mat([[1, 2], [3, 4]]);
+        let expected = mat([[4, 4], [4, 4]]);
+        assert_eq!(a - b, expected);
+        assert_eq!(&a - b, expected);
+        assert_eq!(a - &b, expected);
+        assert_eq!(&a - &b, expected);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_mat_scalar_mul_ref_forms() {
+        let a = mat([[1, 2], [3, 4]]);
+        let expected = mat([[2, 4], [6, 8]]);
+        assert_eq!(a * 2, expected);
+        assert_eq!(&a * 2, expected);
+        assert_eq!(a * &2, expected);
+        assert_eq!(&a * &2, expected);
+    }
+
+    #[test]
+    fn test_mat_fold_counts_positive() {
+        let a = mat([[1, -2], [0, 4]]);
+        let positives = a.fold(0, |count, &x| if x > 0 { count + 1 } else { count });
+        assert_eq!(positives, 2);
+
+        let order = a.fold(std::vec::Vec::new(), |mut seen, &x| {
+            seen.push(x);
+            seen
+        });
+        assert_eq!(order, [1, -2, 0, 4]);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_mat_mul_2x3_by_3x2() {
+        let a = Mat([Vec([1, 2, 3]), Vec([4, 5, 6])]);
+        let b = Mat([Vec([7, 8]), Vec([9, 10]), Vec([11, 12])]);
+        let expected = mat([[58, 64], [139, 154]]);
+        assert_eq!(a * b, expected);
+        assert_eq!(&a * &b, expected);
+    }
+
+    #[test]
+    fn test_mat_assign_ops() {
+        let matrices = [
+            mat([[1, 2], [3, 4]]),
+            mat([[10, 20], [30, 40]]),
+            mat([[100, 200], [300, 400]]),
+        ];
+
+        let mut sum = Mat::default();
+        for m in &matrices {
+            sum += m;
+        }
+        assert_eq!(sum, mat([[111, 222], [333, 444]]));
+
+        sum -= matrices[2];
+        sum -= &matrices[1];
+        assert_eq!(sum, matrices[0]);
+
+        sum += matrices[0];
+        assert_eq!(sum, matrices[0] * 2);
+    }
+
+    #[test]
+    fn test_mat_neg() {
+        let a = mat([[1, -2], [3, -4]]);
+        let expected = mat([[-1, 2], [-3, 4]]);
+        assert_eq!(-a, expected);
+        assert_eq!(-&a, expected);
+    }
+
+    #[test]
+    fn test_mat_powi() {
+        let m = Mat::from([[1, 2, 0], [-1, 0, 3], [2, 1, 1]]);
+        assert_eq!(m.powi(0), Mat::identity());
+        assert_eq!(m.powi(1), m);
+        assert_eq!(m.powi(3), m * m * m);
+        assert_eq!(m.powi(6), m * m * m * m * m * m);
+
+        // paths of length 2 in the graph 0 -> 1 -> 2
+        let adjacency = Mat::from([[0, 1, 0], [0, 0, 1], [0, 0, 0]]);
+        assert_eq!(
+            adjacency.powi(2),
+            Mat::from([[0, 0, 1], [0, 0, 0], [0, 0, 0]])
+        );
+        assert_eq!(adjacency.powi(3), Mat::default());
+    }
+
+    #[test]
+    fn test_mat_row_operations() {
+        let mut m = Mat::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        m.swap_rows(0, 2);
+        assert_eq!(m, Mat::from([[7, 8, 9], [4, 5, 6], [1, 2, 3]]));
+        m.swap_rows(1, 1);
+        assert_eq!(m, Mat::from([[7, 8, 9], [4, 5, 6], [1, 2, 3]]));
+
+        m.scale_row(1, 3);
+        assert_eq!(m, Mat::from([[7, 8, 9], [12, 15, 18], [1, 2, 3]]));
+
+        m.add_scaled_row(0, 2, -7);
+        assert_eq!(m, Mat::from([[0, -6, -12], [12, 15, 18], [1, 2, 3]]));
+        m.add_scaled_row(1, 2, -12);
+        assert_eq!(m, Mat::from([[0, -6, -12], [0, -9, -18], [1, 2, 3]]));
+
+        // the operations on a non-square matrix
+        let mut m = Mat::from([[2.0, 4.0, 6.0], [1.0, 1.0, 1.0]]);
+        m.scale_row(0, 0.5);
+        m.add_scaled_row(1, 0, -1.0);
+        m.swap_rows(0, 1);
+        assert_eq!(m, Mat::from([[0.0, -1.0, -2.0], [1.0, 2.0, 3.0]]));
+    }
 }
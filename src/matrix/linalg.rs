@@ -0,0 +1,548 @@
+//! Norms and orthonormalization of float matrices, and decompositions of square matrices with the
+//! algorithms built on them.
+
+use core::iter::zip;
+
+use crate::error::ShapeError;
+use crate::matrix::Mat;
+use crate::ops::DotProduct;
+use crate::scalar::Float;
+use crate::vector::Vec;
+
+/// Keep the largest of two numbers, for folding norms which are never negative.
+///
+/// A NaN is propagated, so a norm can't hide it behind a finite value.
+fn max<T: Float>(a: T, b: T) -> T {
+    if b > a || b.is_nan() { b } else { a }
+}
+
+impl<T: Float, const M: usize, const N: usize> Mat<M, N, T> {
+    /// Calculate the Frobenius norm, the square root of the sum of the squared
+    /// elements.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::matrix::Mat;
+    /// let m = Mat::from([[1.0, -2.0], [-4.0, 2.0]]);
+    /// assert_eq!(m.frobenius_norm(), 5.0);
+    /// # }
+    /// ```
+    pub fn frobenius_norm(&self) -> T {
+        self.iter_elements()
+            .fold(T::zero(), |acc, &x| acc + x * x)
+            .sqrt()
+    }
+
+    /// Calculate the 1-norm, the largest sum of the absolute values of a column.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::matrix::Mat;
+    /// let m = Mat::from([[1.0, -2.0], [-4.0, 2.0]]);
+    /// assert_eq!(m.norm_l1(), 5.0);
+    /// # }
+    /// ```
+    pub fn norm_l1(&self) -> T {
+        self.iter_cols()
+            .map(|col| col.0.into_iter().fold(T::zero(), |acc, &x| acc + x.abs()))
+            .fold(T::zero(), max)
+    }
+
+    /// Calculate the infinity norm, the largest sum of the absolute values of a row.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::matrix::Mat;
+    /// let m = Mat::from([[1.0, -2.0], [-4.0, 2.0]]);
+    /// assert_eq!(m.norm_inf(), 6.0);
+    /// # }
+    /// ```
+    pub fn norm_inf(&self) -> T {
+        self.iter_rows()
+            .map(|row| row.abs().sum())
+            .fold(T::zero(), max)
+    }
+
+    /// Orthonormalize the columns of the matrix with the modified Gram-Schmidt
+    /// process: each column has its projections onto the previous, already
+    /// orthonormalized columns removed, then is normalized.
+    ///
+    /// The columns should be linearly independent. A column that is exactly a
+    /// combination of the previous ones becomes the zero vector, a nearly
+    /// dependent one is dominated by rounding errors.
+    ///
+    /// This is useful to fix a rotation matrix that drifted after many
+    /// multiplications.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::matrix::Mat;
+    /// let m = Mat::from([[2.0, 1.0], [0.0, 1.0]]);
+    /// assert_eq!(m.orthonormalize(), Mat::from([[1.0, 0.0], [0.0, 1.0]]));
+    /// # }
+    /// ```
+    pub fn orthonormalize(&self) -> Mat<M, N, T> {
+        let mut cols: [Vec<M, T>; N] = core::array::from_fn(|j| self.col(j));
+        for j in 0..N {
+            for k in 0..j {
+                // the projection onto a column which is unit length, or zero
+                let q = cols[k];
+                cols[j] = cols[j] - q * cols[j].dot(q);
+            }
+            cols[j].normalize();
+        }
+        Mat::from_cols(cols)
+    }
+
+    /// Gauss-Jordan elimination with partial pivoting, return the reduced row
+    /// echelon form and the number of pivots.
+    fn gauss_jordan(&self) -> (Mat<M, N, T>, usize) {
+        let (zero, one) = (T::zero(), T::one());
+        let size = (0..M.max(N)).fold(zero, |acc, _| acc + one);
+        let tolerance = size * T::EPSILON * self.norm_inf();
+
+        let mut m = *self;
+        let mut rank = 0;
+        for j in 0..N {
+            if rank == M {
+                break;
+            }
+            let mut pivot = rank;
+            for i in rank + 1..M {
+                if m[i][j].abs() > m[pivot][j].abs() {
+                    pivot = i;
+                }
+            }
+            if m[pivot][j].abs() <= tolerance {
+                // no pivot in this column, clear the rounding errors left
+                for i in rank..M {
+                    m[i][j] = zero;
+                }
+                continue;
+            }
+
+            m.swap_rows(rank, pivot);
+            m.scale_row(rank, m[rank][j].recip());
+            m[rank][j] = one;
+            for i in 0..M {
+                if i != rank {
+                    m.add_scaled_row(i, rank, -m[i][j]);
+                    m[i][j] = zero;
+                }
+            }
+            rank += 1;
+        }
+        (m, rank)
+    }
+
+    /// Calculate the reduced row echelon form of the matrix, with Gauss-Jordan
+    /// elimination.
+    ///
+    /// In this form, the first nonzero element of each row is a 1, the only
+    /// nonzero element of its column, and it's to the right of the one of the
+    /// previous row. Zero rows are at the bottom. The rows of an augmented matrix
+    /// `[A | b]` in this form give the solutions of `A * x == b`, even when `A`
+    /// isn't square or is singular.
+    ///
+    /// An element is considered zero when its absolute value is at most
+    /// `max(M, N) * T::EPSILON * self.norm_inf()`, so rounding errors don't turn
+    /// a dependent row into a pivot.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::matrix::Mat;
+    /// // x + 2y = 5, 2x + 4y = 10: y is free and x = 5 - 2y
+    /// let m = Mat::from([[1.0, 2.0, 5.0], [2.0, 4.0, 10.0]]);
+    /// assert_eq!(m.rref(), Mat::from([[1.0, 2.0, 5.0], [0.0, 0.0, 0.0]]));
+    /// # }
+    /// ```
+    pub fn rref(&self) -> Mat<M, N, T> {
+        self.gauss_jordan().0
+    }
+
+    /// Calculate the rank of the matrix, its number of linearly independent
+    /// rows or columns.
+    ///
+    /// It's the number of nonzero rows of [`Mat::rref`], with the same
+    /// tolerance for nearly dependent rows.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::matrix::Mat;
+    /// let m = Mat::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    /// assert_eq!(m.rank(), 2);
+    /// # }
+    /// ```
+    pub fn rank(&self) -> usize {
+        self.gauss_jordan().1
+    }
+}
+
+impl<T: Float, const N: usize> Mat<N, N, T> {
+    /// LU decomposition stored in a single matrix: the multipliers of `L` below
+    /// the diagonal, `U` on and above it. Also return the row permutation and
+    /// whether it is made of an odd number of swaps.
+    fn lu_compact(&self) -> Option<([Vec<N, T>; N], [usize; N], bool)> {
+        let (zero, one) = (T::zero(), T::one());
+        let size = (0..N).fold(zero, |acc, _| acc + one);
+        let largest = self.iter_elements().fold(zero, |acc, &x| max(acc, x.abs()));
+        let tolerance = size * T::EPSILON * largest;
+
+        let mut rows = self.0;
+        let mut permutation: [usize; N] = core::array::from_fn(|i| i);
+        let mut odd = false;
+
+        for k in 0..N {
+            let mut pivot = k;
+            for i in k + 1..N {
+                if rows[i][k].abs() > rows[pivot][k].abs() {
+                    pivot = i;
+                }
+            }
+            if rows[pivot][k].abs() <= tolerance {
+                return None;
+            }
+            if pivot != k {
+                rows.swap(pivot, k);
+                permutation.swap(pivot, k);
+                odd = !odd;
+            }
+
+            let pivot_row = rows[k];
+            for row in &mut rows[k + 1..] {
+                let factor = row[k] / pivot_row[k];
+                for (x, p) in row.iter_mut().zip(pivot_row).skip(k + 1) {
+                    *x = *x - factor * p;
+                }
+                row[k] = factor;
+            }
+        }
+        Some((rows, permutation, odd))
+    }
+
+    /// Decompose the matrix into a lower triangular matrix `L` with ones on its
+    /// diagonal, an upper triangular matrix `U`, and a row permutation `p` such
+    /// that `P * self == L * U`, `P` being the matrix whose row `i` selects the row
+    /// `p[i]` of `self`.
+    ///
+    /// Partial pivoting is used: at each step the row with the largest absolute
+    /// value in the current column is swapped into the pivot position, which
+    /// keeps the multipliers of `L` within `[-1, 1]`.
+    ///
+    /// Return `None` if the matrix is singular, that is when a column has no
+    /// nonzero pivot left. A pivot is considered zero when its absolute value is
+    /// at most `N * T::EPSILON` times the largest absolute value of the elements,
+    /// so rounding errors don't hide the singularity of a matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec2;
+    /// let m = Mat([Vec2::new(1.0, 2.0), Vec2::new(4.0, 6.0)]);
+    /// let (l, u, p) = m.lu_decompose().unwrap();
+    /// assert_eq!(p, [1, 0]);
+    /// assert_eq!(l, Mat([Vec2::new(1.0, 0.0), Vec2::new(0.25, 1.0)]));
+    /// assert_eq!(u, Mat([Vec2::new(4.0, 6.0), Vec2::new(0.0, 0.5)]));
+    /// # }
+    /// ```
+    pub fn lu_decompose(&self) -> Option<(Mat<N, N, T>, Mat<N, N, T>, [usize; N])> {
+        let (rows, permutation, _) = self.lu_compact()?;
+        let (zero, one) = (T::zero(), T::one());
+        let lower = Mat(core::array::from_fn(|i| {
+            Vec(core::array::from_fn(|j| match j.cmp(&i) {
+                core::cmp::Ordering::Less => rows[i][j],
+                core::cmp::Ordering::Equal => one,
+                core::cmp::Ordering::Greater => zero,
+            }))
+        }));
+        let upper = Mat(core::array::from_fn(|i| {
+            Vec(core::array::from_fn(
+                |j| if j >= i { rows[i][j] } else { zero },
+            ))
+        }));
+        Some((lower, upper, permutation))
+    }
+
+    /// Solve the linear system `self * x == b` for `x`.
+    ///
+    /// The matrix is decomposed with [`Mat::lu_decompose`], then `x` is found by
+    /// forward and back substitution. Return [`ShapeError::Singular`] if the
+    /// matrix is singular, with the same tolerance for rounding errors.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec2;
+    /// // x + y = 3, x - y = 1
+    /// let a = Mat([Vec2::new(1.0, 1.0), Vec2::new(1.0, -1.0)]);
+    /// let x = a.solve(Vec2::new(3.0, 1.0)).unwrap();
+    /// assert_eq!(x, (2.0, 1.0));
+    /// # }
+    /// ```
+    pub fn solve(&self, b: Vec<N, T>) -> Result<Vec<N, T>, ShapeError> {
+        let (rows, permutation, _) = self.lu_compact().ok_or(ShapeError::Singular { size: N })?;
+        let mut x = permutation.map(|p| b[p]);
+
+        // L * y = P * b, L having an implicit unit diagonal
+        for i in 0..N {
+            let sum = zip(&rows[i].0[..i], &x[..i]).fold(T::zero(), |acc, (&l, &y)| acc + l * y);
+            x[i] = x[i] - sum;
+        }
+        // U * x = y
+        for i in (0..N).rev() {
+            let sum =
+                zip(&rows[i].0[i + 1..], &x[i + 1..]).fold(T::zero(), |acc, (&u, &x)| acc + u * x);
+            x[i] = (x[i] - sum) / rows[i][i];
+        }
+        Ok(Vec(x))
+    }
+
+    /// Calculate the determinant of the matrix.
+    ///
+    /// The determinant is the product of the diagonal of `U` from
+    /// [`Mat::lu_decompose`], negated for an odd permutation, or zero if the
+    /// matrix is singular within the tolerance of [`Mat::lu_decompose`].
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec2;
+    /// let m = Mat([Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)]);
+    /// assert_eq!(m.determinant(), -2.0);
+    /// # }
+    /// ```
+    pub fn determinant(&self) -> T {
+        let Some((rows, _, odd)) = self.lu_compact() else {
+            return T::zero();
+        };
+        let det = (0..N).fold(T::one(), |det, i| det * rows[i][i]);
+        if odd { -det } else { det }
+    }
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm")))]
+mod tests {
+    use crate::error::ShapeError;
+    use crate::matrix::Mat;
+    use crate::ops::DotProduct;
+    use crate::scalar::{One, Zero};
+    use crate::vector::Vec;
+
+    fn permutation_matrix<const N: usize>(permutation: [usize; N]) -> Mat<N, N, f64> {
+        Mat(permutation.map(|p| {
+            Vec(core::array::from_fn(|j| {
+                if j == p { f64::one() } else { f64::zero() }
+            }))
+        }))
+    }
+
+    fn assert_lu<const N: usize>(m: Mat<N, N, f64>) {
+        let (l, u, p) = m.lu_decompose().unwrap();
+        for i in 0..N {
+            assert_eq!(l[(i, i)], 1.0);
+            for j in i + 1..N {
+                assert_eq!(l[(i, j)], 0.0);
+                assert_eq!(u[(j, i)], 0.0);
+            }
+        }
+        let pa = permutation_matrix(p) * m;
+        let lu = l * u;
+        for i in 0..N {
+            assert!(pa[i].abs_diff_eq(&lu[i], 1e-12), "{pa:?} != {lu:?}");
+        }
+    }
+
+    #[test]
+    fn test_lu_decompose() {
+        assert_lu(Mat([Vec([1.0, 2.0]), Vec([3.0, 4.0])]));
+        assert_lu(Mat([
+            Vec([2.0, 0.0, 1.0]),
+            Vec([1.0, 3.0, 2.0]),
+            Vec([1.0, 1.0, 2.0]),
+        ]));
+        assert_lu(Mat([
+            Vec([0.0, 1.0, 0.0, 0.0]),
+            Vec([1.0, 0.0, 0.0, 0.0]),
+            Vec([0.0, 0.0, 2.0, 5.0]),
+            Vec([3.0, -1.0, 4.0, 3.0]),
+        ]));
+        assert_lu(Mat::<3, 3, f64>::identity());
+    }
+
+    #[test]
+    fn test_lu_decompose_singular() {
+        let singular = Mat([Vec([1.0, 2.0]), Vec([2.0, 4.0])]);
+        assert!(singular.lu_decompose().is_none());
+        assert!(Mat::<3, 3, f64>::default().lu_decompose().is_none());
+    }
+
+    #[test]
+    fn test_solve() {
+        // 2x + y - z = 8, -3x - y + 2z = -11, -2x + y + 2z = -3
+        let a = Mat([
+            Vec([2.0, 1.0, -1.0]),
+            Vec([-3.0, -1.0, 2.0]),
+            Vec([-2.0, 1.0, 2.0]),
+        ]);
+        let b = Vec([8.0, -11.0, -3.0]);
+        let x = a.solve(b).unwrap();
+        assert!(x.abs_diff_eq(&Vec([2.0, 3.0, -1.0]), 1e-12));
+        assert!((a * x).abs_diff_eq(&b, 1e-12));
+    }
+
+    #[test]
+    fn test_solve_singular() {
+        let singular = Mat([
+            Vec([1.0, 2.0, 3.0]),
+            Vec([2.0, 4.0, 6.0]),
+            Vec([0.0, 1.0, 1.0]),
+        ]);
+        assert_eq!(
+            singular.solve(Vec([1.0, 2.0, 3.0])),
+            Err(ShapeError::Singular { size: 3 })
+        );
+
+        // the last pivot is a rounding error instead of an exact zero, and the
+        // system has no solution
+        let singular = Mat([
+            Vec([1.0, 2.0, 3.0]),
+            Vec([4.0, 5.0, 6.0]),
+            Vec([7.0, 8.0, 9.0]),
+        ]);
+        assert_eq!(
+            singular.solve(Vec([1.0, 2.0, 4.0])),
+            Err(ShapeError::Singular { size: 3 })
+        );
+    }
+
+    #[test]
+    fn test_determinant() {
+        let singular = Mat([Vec([1.0, 2.0]), Vec([2.0, 4.0])]);
+        assert_eq!(singular.determinant(), 0.0);
+
+        let singular = Mat([
+            Vec([1.0_f64, 2.0, 3.0]),
+            Vec([4.0, 5.0, 6.0]),
+            Vec([7.0, 8.0, 9.0]),
+        ]);
+        assert!(singular.lu_decompose().is_none());
+        assert_eq!(singular.determinant(), 0.0);
+
+        let m = Mat([
+            Vec([2.0_f64, 0.0, 1.0]),
+            Vec([1.0, 3.0, 2.0]),
+            Vec([1.0, 1.0, 2.0]),
+        ]);
+        assert!((m.determinant() - 6.0).abs() < 1e-12);
+
+        let m = Mat([
+            Vec([0.0, 1.0, 0.0, 0.0]),
+            Vec([1.0, 0.0, 0.0, 0.0]),
+            Vec([0.0, 0.0, 2.0, 0.0]),
+            Vec([0.0, 0.0, 0.0, 3.0]),
+        ]);
+        assert_eq!(m.determinant(), -6.0);
+        assert_eq!(Mat::<4, 4, f64>::identity().determinant(), 1.0);
+    }
+
+    #[test]
+    fn test_norms() {
+        let m = Mat([Vec([3.0, -1.0]), Vec([-2.0, 5.0])]);
+        // sqrt(9 + 1 + 4 + 25)
+        assert_eq!(m.frobenius_norm(), 39.0_f64.sqrt());
+        // columns: 3 + 2, 1 + 5
+        assert_eq!(m.norm_l1(), 6.0);
+        // rows: 3 + 1, 2 + 5
+        assert_eq!(m.norm_inf(), 7.0);
+
+        let wide = Mat([Vec([1.0, -1.0, 4.0])]);
+        assert_eq!(wide.norm_l1(), 4.0);
+        assert_eq!(wide.norm_inf(), 6.0);
+        assert_eq!(Mat::<0, 0, f64>::default().frobenius_norm(), 0.0);
+
+        let nan = Mat([Vec([1.0, f64::NAN]), Vec([5.0, 1.0])]);
+        assert!(nan.norm_l1().is_nan());
+        assert!(nan.norm_inf().is_nan());
+    }
+
+    fn assert_orthonormal_cols<const M: usize, const N: usize>(m: Mat<M, N, f64>) {
+        for i in 0..N {
+            assert!((m.col(i).length() - 1.0).abs() < 1e-12, "{m:?}");
+            for j in i + 1..N {
+                assert!(m.col(i).dot(m.col(j)).abs() < 1e-12, "{m:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_orthonormalize() {
+        // a rotation with some drift
+        let drifted = Mat::<3, 3, f64>::rotation_x(0.4) * Mat::rotation_z(1.1) * 1.01
+            + Mat([
+                Vec([0.0, 0.003, 0.0]),
+                Vec([-0.002, 0.0, 0.001]),
+                Vec([0.0, 0.0, 0.004]),
+            ]);
+        let fixed = drifted.orthonormalize();
+        assert_orthonormal_cols(fixed);
+        // the first column keeps its direction
+        assert!(
+            fixed
+                .col(0)
+                .abs_diff_eq(&drifted.col(0).normalized(), 1e-12)
+        );
+
+        let tall = Mat([Vec([1.0, 1.0]), Vec([2.0, 0.0]), Vec([0.0, 3.0])]);
+        assert_orthonormal_cols(tall.orthonormalize());
+    }
+
+    #[test]
+    fn test_rref() {
+        // x + 2y - z = -4, 2x + 3y - z = -11, -2x - 3z = 22
+        let m = Mat::from([
+            [1.0, 2.0, -1.0, -4.0],
+            [2.0, 3.0, -1.0, -11.0],
+            [-2.0, 0.0, -3.0, 22.0],
+        ]);
+        let expected = Mat::from([
+            [1.0, 0.0, 0.0, -8.0],
+            [0.0, 1.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0, -2.0],
+        ]);
+        let rref = m.rref();
+        for i in 0..3 {
+            assert!(rref[i].abs_diff_eq(&expected[i], 1e-12), "{rref:?}");
+        }
+
+        // the zero column is skipped, the dependent row ends at the bottom
+        let m = Mat::from([[0.0, 2.0, 4.0], [0.0, 1.0, 2.0], [0.0, 3.0, 7.0]]);
+        assert_eq!(
+            m.rref(),
+            Mat::from([[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]])
+        );
+    }
+
+    #[test]
+    fn test_rank() {
+        assert_eq!(Mat::<3, 3, f64>::identity().rank(), 3);
+        assert_eq!(Mat::<3, 3, f64>::default().rank(), 0);
+        assert_eq!(Mat::from([[1.0, 2.0], [2.0, 4.0]]).rank(), 1);
+        assert_eq!(Mat::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]).rank(), 2);
+        assert_eq!(Mat::from([[1.0, 2.0], [3.0, 4.0], [4.0, 6.0]]).rank(), 2);
+        // the third row is the sum of the first two, up to rounding
+        let m = Mat::from([[0.1, 0.2, 0.3], [0.7, 0.5, 0.3], [0.8, 0.7, 0.6]]);
+        assert_eq!(m.rank(), 2);
+        // the outer product of two vectors has rank 1
+        let outer: Mat<4, 3, f64> = Vec([1.0, -2.0, 0.5, 3.0]).outer(Vec([0.3, 1.7, -2.2]));
+        assert_eq!(outer.rank(), 1);
+    }
+}
@@ -0,0 +1,228 @@
+//! Constructors for the common geometric transformations.
+
+use crate::matrix::Mat;
+use crate::ops::{CrossProduct, DotProduct};
+use crate::scalar::Float;
+use crate::vector::{Vec, Vec3};
+
+impl<T: Float> Mat<2, 2, T> {
+    /// Create a counterclockwise rotation by `angle` radians.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec2;
+    /// let m = Mat::<2, 2, f32>::rotation(std::f32::consts::FRAC_PI_2);
+    /// let v = m * Vec2::new(1.0, 0.0);
+    /// assert!(v.abs_diff_eq(&Vec2::new(0.0, 1.0), 1e-6));
+    /// # }
+    /// ```
+    pub fn rotation(angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        Mat([Vec([cos, -sin]), Vec([sin, cos])])
+    }
+}
+
+impl<T: Float> Mat<3, 3, T> {
+    /// Create a rotation by `angle` radians around the x axis.
+    ///
+    /// The rotation is counterclockwise when looking from the positive x axis
+    /// toward the origin, so it turns y into z.
+    pub fn rotation_x(angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let (zero, one) = (T::zero(), T::one());
+        Mat([
+            Vec([one, zero, zero]),
+            Vec([zero, cos, -sin]),
+            Vec([zero, sin, cos]),
+        ])
+    }
+
+    /// Create a rotation by `angle` radians around the y axis, turning z into x.
+    pub fn rotation_y(angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let (zero, one) = (T::zero(), T::one());
+        Mat([
+            Vec([cos, zero, sin]),
+            Vec([zero, one, zero]),
+            Vec([-sin, zero, cos]),
+        ])
+    }
+
+    /// Create a rotation by `angle` radians around the z axis, turning x into y.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec3;
+    /// let m = Mat::<3, 3, f32>::rotation_z(std::f32::consts::FRAC_PI_2);
+    /// let v = m * Vec3::new(1.0, 0.0, 0.0);
+    /// assert!(v.abs_diff_eq(&Vec3::new(0.0, 1.0, 0.0), 1e-6));
+    /// # }
+    /// ```
+    pub fn rotation_z(angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let (zero, one) = (T::zero(), T::one());
+        Mat([
+            Vec([cos, -sin, zero]),
+            Vec([sin, cos, zero]),
+            Vec([zero, zero, one]),
+        ])
+    }
+}
+
+impl<T: Float> Mat<4, 4, T> {
+    /// Create a homogeneous translation by `offset`.
+    ///
+    /// The offset is stored in the last column, so it moves points (`w = 1`)
+    /// but not directions (`w = 0`).
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::{Vec3, Vec4};
+    /// let m = Mat::translation(Vec3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(m * Vec4::new(1.0, 1.0, 1.0, 1.0), (2.0, 3.0, 4.0, 1.0));
+    /// assert_eq!(m * Vec4::new(1.0, 1.0, 1.0, 0.0), (1.0, 1.0, 1.0, 0.0));
+    /// # }
+    /// ```
+    pub fn translation(offset: Vec3<T>) -> Self {
+        Self::from_scale_rotation_translation(Vec3::splat(T::one()), Mat::identity(), offset)
+    }
+
+    /// Create a homogeneous scale by `factors` along each axis.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::{Vec3, Vec4};
+    /// let m = Mat::scale(Vec3::new(2.0, 3.0, 4.0));
+    /// assert_eq!(m * Vec4::new(1.0, 1.0, 1.0, 1.0), (2.0, 3.0, 4.0, 1.0));
+    /// # }
+    /// ```
+    pub fn scale(factors: Vec3<T>) -> Self {
+        Self::from_scale_rotation_translation(factors, Mat::identity(), Vec3::splat(T::zero()))
+    }
+
+    /// Create a homogeneous transform that scales, then rotates, then translates.
+    ///
+    /// This is the same as `translation(translation) * rotation * scale(scale)`,
+    /// with `rotation` extended to a 4x4 matrix.
+    pub fn from_scale_rotation_translation(
+        scale: Vec3<T>,
+        rotation: Mat<3, 3, T>,
+        translation: Vec3<T>,
+    ) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let [r0, r1, r2] = rotation.0.map(|row| row.component_mul(scale));
+        let [tx, ty, tz] = translation.0;
+        Mat([
+            r0.extend(tx),
+            r1.extend(ty),
+            r2.extend(tz),
+            Vec([zero, zero, zero, one]),
+        ])
+    }
+
+    /// Create a right-handed view matrix for a camera at `eye` looking at `target`.
+    ///
+    /// In view space the camera sits at the origin and looks toward `-z`, with
+    /// `+x` to its right and `+y` up. `up` doesn't need to be orthogonal to the
+    /// view direction, but it must not be parallel to it.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::matrix::Mat;
+    /// use isochro::vector::Vec3;
+    /// let eye = Vec3::new(0.0, 0.0, 5.0);
+    /// let view = Mat::look_at(eye, Vec3::splat(0.0), Vec3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(view * eye.extend(1.0), (0.0, 0.0, 0.0, 1.0));
+    /// # }
+    /// ```
+    pub fn look_at(eye: Vec3<T>, target: Vec3<T>, up: Vec3<T>) -> Self {
+        let forward = (target - eye).normalized();
+        let right = forward.cross(up).normalized();
+        let up = right.cross(forward);
+        let zero = T::zero();
+        Mat([
+            right.extend(-right.dot(eye)),
+            up.extend(-up.dot(eye)),
+            (-forward).extend(forward.dot(eye)),
+            Vec([zero, zero, zero, T::one()]),
+        ])
+    }
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm")))]
+mod tests {
+    use core::f64::consts::FRAC_PI_2;
+
+    use crate::matrix::Mat;
+    use crate::vector::{Vec2, Vec3};
+
+    #[test]
+    fn test_rotation_2d() {
+        let v = Mat::rotation(FRAC_PI_2) * Vec2::new(1.0, 0.0);
+        assert!(v.abs_diff_eq(&Vec2::new(0.0, 1.0), 1e-12));
+    }
+
+    #[test]
+    fn test_translation_moves_points() {
+        let offset = Vec3::new(1.0, -2.0, 0.5);
+        let point = Vec3::new(3.0, 4.0, 5.0);
+        let moved = Mat::translation(offset) * point.extend(1.0);
+        assert_eq!(moved, (point + offset).extend(1.0));
+    }
+
+    #[test]
+    fn test_scale_rotation_translation() {
+        let scale = Vec3::new(2.0, 3.0, 4.0);
+        let rotation = Mat::rotation_z(FRAC_PI_2);
+        let translation = Vec3::new(1.0, 2.0, 3.0);
+        let mut rotation4 = Mat::<4, 4, f64>::identity();
+        for i in 0..3 {
+            rotation4[i] = rotation[i].extend(0.0);
+        }
+
+        let combined = Mat::from_scale_rotation_translation(scale, rotation, translation);
+        let composed = Mat::translation(translation) * rotation4 * Mat::scale(scale);
+        assert_eq!(combined, composed);
+
+        let moved = combined * Vec3::new(1.0, 0.0, 0.0).extend(1.0);
+        assert!(moved.abs_diff_eq(&Vec3::new(1.0, 4.0, 3.0).extend(1.0), 1e-12));
+    }
+
+    #[test]
+    fn test_look_at() {
+        let eye = Vec3::new(1.0, 2.0, 3.0);
+        let target = Vec3::new(4.0, 2.0, 3.0);
+        let view = Mat::look_at(eye, target, Vec3::new(0.0, 1.0, 0.0));
+
+        let origin = view * eye.extend(1.0);
+        assert!(origin.abs_diff_eq(&Vec3::splat(0.0).extend(1.0), 1e-12));
+
+        // the target is straight ahead, 3 units down the -z axis
+        let ahead = view * target.extend(1.0);
+        assert!(ahead.abs_diff_eq(&Vec3::new(0.0, 0.0, -3.0).extend(1.0), 1e-12));
+
+        // looking along +x with y up, the world +z is on the right
+        let right = view * Vec3::new(0.0, 0.0, 1.0).extend(0.0);
+        assert!(right.abs_diff_eq(&Vec3::new(1.0, 0.0, 0.0).extend(0.0), 1e-12));
+    }
+
+    #[test]
+    fn test_rotation_3d() {
+        let x = Vec3::new(1.0, 0.0, 0.0);
+        let y = Vec3::new(0.0, 1.0, 0.0);
+        let z = Vec3::new(0.0, 0.0, 1.0);
+
+        assert!((Mat::rotation_x(FRAC_PI_2) * y).abs_diff_eq(&z, 1e-12));
+        assert!((Mat::rotation_y(FRAC_PI_2) * z).abs_diff_eq(&x, 1e-12));
+        assert!((Mat::rotation_z(FRAC_PI_2) * x).abs_diff_eq(&y, 1e-12));
+    }
+}
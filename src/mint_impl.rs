@@ -0,0 +1,124 @@
+//! Conversions with the `mint` crate, enabled by the `mint` feature.
+//!
+//! `Vec2`, `Vec3` and `Vec4` convert to and from `mint::Vector2`, `Vector3` and
+//! `Vector4`, and the square matrices `Mat<N, N, T>` with `N` in 2, 3 and 4
+//! convert to and from both `mint::RowMatrixN` and `mint::ColumnMatrixN`.
+//!
+//! The conversions keep the mathematical matrix: element `(i, j)` of a `Mat`
+//! is element `(i, j)` of the mint matrix. A `Mat` is stored by rows, so it maps
+//! directly to a `RowMatrix`, while converting to a `ColumnMatrix` transposes
+//! the storage. As the vectors are column vectors, a translation stored in the
+//! last column of a `Mat<4, 4, T>` ends up in the `w` column of a
+//! `ColumnMatrix4`, which is what the ecosystem expects.
+
+use crate::matrix::Mat;
+use crate::vector::Vec;
+
+macro_rules! impl_mint_vec {
+    ($($dim:literal => $mint:ident),*) => {
+        $(
+            impl<T> From<mint::$mint<T>> for Vec<$dim, T> {
+                fn from(v: mint::$mint<T>) -> Self {
+                    Vec(v.into())
+                }
+            }
+
+            impl<T> From<Vec<$dim, T>> for mint::$mint<T> {
+                fn from(v: Vec<$dim, T>) -> Self {
+                    v.0.into()
+                }
+            }
+
+            impl<T> mint::IntoMint for Vec<$dim, T> {
+                type MintType = mint::$mint<T>;
+            }
+        )*
+    };
+}
+
+impl_mint_vec!(2 => Vector2, 3 => Vector3, 4 => Vector4);
+
+macro_rules! impl_mint_mat {
+    ($($dim:literal => $row:ident, $col:ident { $($field:ident),+ }),*) => {
+        $(
+            impl<T> From<mint::$row<T>> for Mat<$dim, $dim, T> {
+                fn from(m: mint::$row<T>) -> Self {
+                    Mat([$(m.$field.into()),+])
+                }
+            }
+
+            impl<T> From<Mat<$dim, $dim, T>> for mint::$row<T> {
+                fn from(m: Mat<$dim, $dim, T>) -> Self {
+                    let [$($field),+] = m.0;
+                    mint::$row {
+                        $($field: $field.into()),+
+                    }
+                }
+            }
+
+            impl<T> From<mint::$col<T>> for Mat<$dim, $dim, T> {
+                fn from(m: mint::$col<T>) -> Self {
+                    mint::$row::from(m).into()
+                }
+            }
+
+            impl<T> From<Mat<$dim, $dim, T>> for mint::$col<T> {
+                fn from(m: Mat<$dim, $dim, T>) -> Self {
+                    mint::$row::from(m).into()
+                }
+            }
+
+            impl<T> mint::IntoMint for Mat<$dim, $dim, T> {
+                type MintType = mint::$col<T>;
+            }
+        )*
+    };
+}
+
+impl_mint_mat!(
+    2 => RowMatrix2, ColumnMatrix2 { x, y },
+    3 => RowMatrix3, ColumnMatrix3 { x, y, z },
+    4 => RowMatrix4, ColumnMatrix4 { x, y, z, w }
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Mat;
+    use crate::vector::{Vec2, Vec3, Vec4};
+
+    #[test]
+    fn test_vec_round_trip() {
+        let v = Vec3::new(1, 2, 3);
+        let m: mint::Vector3<i32> = v.into();
+        assert_eq!((m.x, m.y, m.z), (1, 2, 3));
+        assert_eq!(Vec3::from(m), v);
+
+        let v = Vec2::new(1.0, 2.0);
+        assert_eq!(Vec2::from(mint::Vector2::from(v)), v);
+        let v = Vec4::new(1, 2, 3, 4);
+        assert_eq!(Vec4::from(mint::Vector4::from(v)), v);
+    }
+
+    #[test]
+    fn test_mat_round_trip() {
+        let mat = Mat([Vec2::new(1, 2), Vec2::new(3, 4)]);
+        let col: mint::ColumnMatrix2<i32> = mat.into();
+        assert_eq!(<[[i32; 2]; 2]>::from(col), [[1, 3], [2, 4]]);
+        assert_eq!(Mat::from(col), mat);
+        let mat = Mat::<3, 3, i32>::identity();
+        assert_eq!(Mat::from(mint::ColumnMatrix3::from(mat)), mat);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_transform_round_trip() {
+        let mat = Mat::translation(Vec3::new(5.0, 6.0, 7.0));
+        let col: mint::ColumnMatrix4<f64> = mat.into();
+        assert_eq!((col.w.x, col.w.y, col.w.z, col.w.w), (5.0, 6.0, 7.0, 1.0));
+        assert_eq!(Mat::from(col), mat);
+
+        let row: mint::RowMatrix4<f64> = mat.into();
+        assert_eq!((row.x.w, row.y.w, row.z.w), (5.0, 6.0, 7.0));
+        assert_eq!(Mat::from(row), mat);
+    }
+}
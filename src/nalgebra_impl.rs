@@ -0,0 +1,89 @@
+//! Conversions with the `nalgebra` crate, enabled by the `nalgebra` feature.
+//!
+//! A `Vec<D, T>` converts to and from a `nalgebra::SVector<T, D>`, which covers
+//! `Vector2`, `Vector3` and `Vector4`, and a `Mat<M, N, T>` to and from a
+//! `nalgebra::SMatrix<T, M, N>`, which covers `Matrix2`, `Matrix3`, `Matrix4`
+//! and the non-square matrices.
+//!
+//! The conversions keep the mathematical matrix: element `(i, j)` of a `Mat`
+//! is element `(i, j)` of the nalgebra matrix. Both libraries use column
+//! vectors, so a translation stored in the last column of a `Mat<4, 4, T>` stays
+//! in the last column of a `Matrix4`. Only the storage differs: `Mat` is stored
+//! by rows and nalgebra by columns.
+
+use nalgebra::{ArrayStorage, SMatrix, SVector, Scalar};
+
+use crate::matrix::Mat;
+use crate::vector::Vec;
+
+impl<T, const D: usize> From<SVector<T, D>> for Vec<D, T> {
+    fn from(v: SVector<T, D>) -> Self {
+        let [column] = v.data.0;
+        Vec(column)
+    }
+}
+
+impl<T, const D: usize> From<Vec<D, T>> for SVector<T, D> {
+    fn from(v: Vec<D, T>) -> Self {
+        SVector::from_array_storage(ArrayStorage([v.0]))
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<SMatrix<T, M, N>> for Mat<M, N, T> {
+    fn from(m: SMatrix<T, M, N>) -> Self {
+        Mat::from_cols(m.data.0.map(Vec))
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize> From<Mat<M, N, T>> for SMatrix<T, M, N> {
+    fn from(m: Mat<M, N, T>) -> Self {
+        SMatrix::from_row_iterator(m.into_iter().flatten())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Matrix2, Matrix2x3, Vector2, Vector3, Vector4};
+
+    use crate::matrix::Mat;
+    use crate::vector::{Vec2, Vec3, Vec4};
+
+    #[test]
+    fn test_vec_round_trip() {
+        let v = Vec3::new(1.0f32, 2.0, 3.0);
+        let n: Vector3<f32> = v.into();
+        assert_eq!(n, Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vec3::from(n), v);
+
+        let v = Vec2::new(1, 2);
+        assert_eq!(Vec2::from(Vector2::from(v)), v);
+        let v = Vec4::new(1.0f64, 2.0, 3.0, 4.0);
+        assert_eq!(Vec4::from(Vector4::from(v)), v);
+    }
+
+    #[test]
+    fn test_mat_round_trip() {
+        let mat = Mat::from([[1, 2], [3, 4]]);
+        let n = Matrix2::from(mat);
+        assert_eq!(n, Matrix2::new(1, 2, 3, 4));
+        assert_eq!(n * Vector2::new(1, 1), Vector2::from(mat * Vec2::new(1, 1)));
+        assert_eq!(Mat::from(n), mat);
+
+        let mat = Mat::from([[1, 2, 3], [4, 5, 6]]);
+        let n = Matrix2x3::from(mat);
+        assert_eq!(n[(1, 0)], 4);
+        assert_eq!(Mat::from(n), mat);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_transform_round_trip() {
+        let mat = Mat::translation(Vec3::new(5.0f32, 6.0, 7.0));
+        let n: nalgebra::Matrix4<f32> = mat.into();
+        assert_eq!(
+            n,
+            nalgebra::Matrix4::new_translation(&Vector3::new(5.0, 6.0, 7.0))
+        );
+        assert_eq!(Mat::from(n), mat);
+    }
+}
@@ -11,3 +11,12 @@ pub trait DotProduct<Rhs = Self> {
 
     fn dot(self, other: Rhs) -> Self::Output;
 }
+
+/// The cross product operation.
+///
+/// This trait provide a way to do a cross product of a given type for the isochro lib.
+pub trait CrossProduct<Rhs = Self> {
+    type Output;
+
+    fn cross(self, other: Rhs) -> Self::Output;
+}
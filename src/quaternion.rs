@@ -0,0 +1,293 @@
+//! A quaternion type to represent 3D rotations.
+//!
+//! Unlike euler angles, quaternions don't suffer from gimbal lock and can be
+//! smoothly interpolated. Rotations are represented by unit quaternions.
+//! # Examples
+//! ```
+//! # #[cfg(any(feature = "std", feature = "libm"))] {
+//! use isochro::quaternion::Quat;
+//! use isochro::vector::Vec3;
+//! let q = Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), std::f32::consts::FRAC_PI_2);
+//! let v = q.rotate(Vec3::new(1.0, 0.0, 0.0));
+//! assert!(v.abs_diff_eq(&Vec3::new(0.0, 1.0, 0.0), 1e-6));
+//! # }
+//! ```
+
+use core::ops::Mul;
+
+use crate::macros::forward_ref_binop;
+use crate::matrix::Mat;
+use crate::ops::{CrossProduct, DotProduct};
+use crate::scalar::Float;
+use crate::vector::{Vec, Vec3, Vec4};
+
+/// A quaternion `xi + yj + zk + w`, stored as a `Vec4` of its `x, y, z, w` components.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct Quat<T>(pub Vec4<T>);
+
+impl<T> Quat<T> {
+    pub const fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self(Vec4::new(x, y, z, w))
+    }
+}
+
+impl<T: Float> Quat<T> {
+    /// Create the quaternion of the identity rotation.
+    pub fn identity() -> Self {
+        let zero = T::zero();
+        Self::new(zero, zero, zero, T::one())
+    }
+
+    /// Create a rotation by `angle` radians around `axis`, counterclockwise when
+    /// looking from the tip of `axis` toward the origin.
+    ///
+    /// The axis is normalized, it doesn't need to be unit length.
+    pub fn from_axis_angle(axis: Vec3<T>, angle: T) -> Self {
+        let half = angle / (T::one() + T::one());
+        let [x, y, z] = (axis.normalized() * half.sin()).0;
+        Self::new(x, y, z, half.cos())
+    }
+
+    /// Get the conjugate `-xi - yj - zk + w`, which is the inverse rotation for
+    /// a unit quaternion.
+    pub fn conjugate(self) -> Self {
+        let [x, y, z, w] = self.0.0;
+        Self::new(-x, -y, -z, w)
+    }
+
+    /// Get the quaternion scaled to unit length, see [`Vec::normalized`].
+    pub fn normalized(self) -> Self {
+        Self(self.0.normalized())
+    }
+
+    /// Normalize the quaternion in place, see [`Vec::normalized`].
+    pub fn normalize(&mut self) {
+        self.0.normalize();
+    }
+
+    /// Spherically interpolate between two unit quaternions, rotating at constant speed.
+    ///
+    /// The interpolation takes the shortest path: if the quaternions are more than
+    /// half a turn apart, `other` is negated (which is the same rotation).
+    /// When the quaternions are so close that the angle between them is tiny, it
+    /// falls back to a normalized linear interpolation to avoid dividing by a
+    /// near-zero sine.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::quaternion::Quat;
+    /// use isochro::vector::Vec3;
+    /// let z = Vec3::new(0.0, 0.0, 1.0);
+    /// let a = Quat::from_axis_angle(z, 0.0);
+    /// let b = Quat::from_axis_angle(z, 1.0);
+    /// let c = a.slerp(b, 0.25);
+    /// assert!(c.0.abs_diff_eq(&Quat::from_axis_angle(z, 0.25).0, 1e-12));
+    /// # }
+    /// ```
+    pub fn slerp(self, other: Quat<T>, t: T) -> Quat<T> {
+        let one = T::one();
+        let mut other = other.0;
+        let mut cos = self.0.dot(other);
+        if cos < T::zero() {
+            other = -other;
+            cos = -cos;
+        }
+
+        if one - cos < T::EPSILON.sqrt() {
+            return Quat(self.0.lerp(other, t)).normalized();
+        }
+
+        let angle = cos.acos();
+        let sin = angle.sin();
+        let a = ((one - t) * angle).sin() / sin;
+        let b = (t * angle).sin() / sin;
+        Quat(self.0 * a + other * b)
+    }
+
+    /// Rotate a vector, the quaternion must be unit length.
+    pub fn rotate(self, v: Vec3<T>) -> Vec3<T> {
+        // v + 2w(q × v) + 2q × (q × v), with q the vector part
+        let [x, y, z, w] = self.0.0;
+        let q = Vec3::new(x, y, z);
+        let t = q.cross(v) * (T::one() + T::one());
+        v + t * w + q.cross(t)
+    }
+
+    /// Convert the rotation to a 3x3 matrix, the quaternion must be unit length.
+    pub fn to_mat3(self) -> Mat<3, 3, T> {
+        let [x, y, z, w] = self.0.0;
+        let one = T::one();
+        let two = one + one;
+        Mat([
+            Vec([
+                one - two * (y * y + z * z),
+                two * (x * y - z * w),
+                two * (x * z + y * w),
+            ]),
+            Vec([
+                two * (x * y + z * w),
+                one - two * (x * x + z * z),
+                two * (y * z - x * w),
+            ]),
+            Vec([
+                two * (x * z - y * w),
+                two * (y * z + x * w),
+                one - two * (x * x + y * y),
+            ]),
+        ])
+    }
+
+    /// Convert the rotation to a homogeneous 4x4 matrix, the quaternion must be unit length.
+    pub fn to_mat4(self) -> Mat<4, 4, T> {
+        Mat::from_scale_rotation_translation(
+            Vec3::splat(T::one()),
+            self.to_mat3(),
+            Vec3::splat(T::zero()),
+        )
+    }
+}
+
+impl<T: Float> Mul<Quat<T>> for Quat<T> {
+    type Output = Quat<T>;
+
+    /// Compose two rotations, `a * b` applies `b` first, then `a`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::quaternion::Quat;
+    /// use isochro::vector::Vec3;
+    /// let z = Vec3::new(0.0, 0.0, 1.0);
+    /// let a = Quat::from_axis_angle(z, 0.25);
+    /// let b = Quat::from_axis_angle(z, 0.5);
+    /// let c = Quat::from_axis_angle(z, 0.75);
+    /// assert!((a * b).0.abs_diff_eq(&c.0, 1e-12));
+    /// # }
+    /// ```
+    fn mul(self, rhs: Quat<T>) -> Self::Output {
+        let [x1, y1, z1, w1] = self.0.0;
+        let [x2, y2, z2, w2] = rhs.0.0;
+        Quat::new(
+            w1 * x2 + x1 * w2 + y1 * z2 - z1 * y2,
+            w1 * y2 - x1 * z2 + y1 * w2 + z1 * x2,
+            w1 * z2 + x1 * y2 - y1 * x2 + z1 * w2,
+            w1 * w2 - x1 * x2 - y1 * y2 - z1 * z2,
+        )
+    }
+}
+
+forward_ref_binop! {
+    impl<T> Mul<Quat<T>>, mul for Quat<T>
+    where
+        T: Float,
+}
+
+impl<T: Float> Mul<Vec3<T>> for Quat<T> {
+    type Output = Vec3<T>;
+
+    /// Rotate a vector, see [`Quat::rotate`].
+    fn mul(self, rhs: Vec3<T>) -> Self::Output {
+        self.rotate(rhs)
+    }
+}
+
+forward_ref_binop! {
+    impl<T> Mul<Vec3<T>>, mul for Quat<T>
+    where
+        T: Float,
+}
+
+impl<T: Float> From<Quat<T>> for Mat<3, 3, T> {
+    fn from(q: Quat<T>) -> Self {
+        q.to_mat3()
+    }
+}
+
+impl<T: Float> From<Quat<T>> for Mat<4, 4, T> {
+    fn from(q: Quat<T>) -> Self {
+        q.to_mat4()
+    }
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm")))]
+mod tests {
+    use super::*;
+
+    fn mat_approx_eq<const M: usize, const N: usize>(a: Mat<M, N, f64>, b: Mat<M, N, f64>) -> bool {
+        a.0.iter().zip(&b.0).all(|(a, b)| a.abs_diff_eq(b, 1e-12))
+    }
+
+    #[test]
+    fn test_compose_matches_matrices() {
+        let a = Quat::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7);
+        let b = Quat::from_axis_angle(Vec3::new(-1.0, 0.5, 0.0), 1.9);
+        assert!(mat_approx_eq((a * b).to_mat3(), a.to_mat3() * b.to_mat3()));
+        assert!(mat_approx_eq((a * b).to_mat4(), a.to_mat4() * b.to_mat4()));
+    }
+
+    #[test]
+    fn test_rotate_matches_matrix() {
+        let q = Quat::from_axis_angle(Vec3::new(0.3, -1.0, 2.0), 2.2);
+        let v = Vec3::new(4.0, -5.0, 6.0);
+        assert!((q * v).abs_diff_eq(&(q.to_mat3() * v), 1e-12));
+    }
+
+    #[test]
+    fn test_axis_angle() {
+        let q = Quat::from_axis_angle(Vec3::new(2.0, 0.0, 0.0), core::f64::consts::FRAC_PI_2);
+        let v = q.rotate(Vec3::new(0.0, 1.0, 0.0));
+        assert!(v.abs_diff_eq(&Vec3::new(0.0, 0.0, 1.0), 1e-12));
+        assert!(mat_approx_eq(
+            q.to_mat3(),
+            Mat::rotation_x(core::f64::consts::FRAC_PI_2)
+        ));
+    }
+
+    #[test]
+    fn test_slerp_halfway() {
+        let z = Vec3::new(0.0, 0.0, 1.0);
+        let quarter_turn = Quat::from_axis_angle(z, core::f64::consts::FRAC_PI_2);
+        let half = Quat::identity().slerp(quarter_turn, 0.5);
+        let expected = Quat::from_axis_angle(z, core::f64::consts::FRAC_PI_4);
+        assert!(half.0.abs_diff_eq(&expected.0, 1e-12));
+
+        let v = half.rotate(Vec3::new(1.0, 0.0, 0.0));
+        let diagonal = core::f64::consts::FRAC_1_SQRT_2;
+        assert!(v.abs_diff_eq(&Vec3::new(diagonal, diagonal, 0.0), 1e-12));
+    }
+
+    #[test]
+    fn test_slerp_shortest_path() {
+        let z = Vec3::new(0.0, 0.0, 1.0);
+        let a = Quat::from_axis_angle(z, 0.1);
+        // the same rotation as an angle of 0.3, on the other side of the hypersphere
+        let b = Quat(-Quat::from_axis_angle(z, 0.3).0);
+        let mid = a.slerp(b, 0.5);
+        assert!(mid.0.abs_diff_eq(&Quat::from_axis_angle(z, 0.2).0, 1e-12));
+    }
+
+    #[test]
+    fn test_slerp_tiny_angle() {
+        let z = Vec3::new(0.0, 0.0, 1.0);
+        let a = Quat::from_axis_angle(z, 1.0);
+        let b = Quat::from_axis_angle(z, 1.0 + 1e-10);
+        assert!(a.slerp(b, 0.5).0.abs_diff_eq(&a.0, 1e-9));
+        assert!(a.slerp(a, 0.5).0.abs_diff_eq(&a.0, 1e-12));
+    }
+
+    #[test]
+    fn test_conjugate_is_inverse() {
+        let q = Quat::from_axis_angle(Vec3::new(1.0, 1.0, 0.0), 1.0);
+        assert!(
+            (q * q.conjugate())
+                .0
+                .abs_diff_eq(&Quat::identity().0, 1e-12)
+        );
+
+        let mut scaled = Quat(q.0 * 3.0);
+        scaled.normalize();
+        assert!(scaled.0.abs_diff_eq(&q.0, 1e-12));
+    }
+}
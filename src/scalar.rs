@@ -0,0 +1,339 @@
+//! Scalar traits.
+//!
+//! This module provide the traits used to bound the element type of vectors
+//! and matrices when an operation need more than the standard operators.
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A type with an additive identity.
+///
+/// With the `num-traits` feature, this trait is implemented for every type
+/// implementing `num_traits::Zero` instead of only the primitive types.
+pub trait Zero {
+    /// Get the additive identity.
+    fn zero() -> Self;
+}
+
+/// A type with a multiplicative identity.
+///
+/// With the `num-traits` feature, this trait is implemented for every type
+/// implementing `num_traits::One` instead of only the primitive types.
+pub trait One {
+    /// Get the multiplicative identity.
+    fn one() -> Self;
+}
+
+#[cfg(not(feature = "num-traits"))]
+macro_rules! impl_zero_one {
+    ($zero:literal, $one:literal => $($t:ty),*) => {
+        $(
+            impl Zero for $t {
+                #[inline]
+                fn zero() -> Self {
+                    $zero
+                }
+            }
+
+            impl One for $t {
+                #[inline]
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(not(feature = "num-traits"))]
+impl_zero_one!(0, 1 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+#[cfg(not(feature = "num-traits"))]
+impl_zero_one!(0.0, 1.0 => f32, f64);
+
+#[cfg(feature = "num-traits")]
+impl<T: num_traits::Zero> Zero for T {
+    #[inline]
+    fn zero() -> Self {
+        num_traits::Zero::zero()
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: num_traits::One> One for T {
+    #[inline]
+    fn one() -> Self {
+        num_traits::One::one()
+    }
+}
+
+/// A number that can be negative.
+pub trait Signed: Sized + Neg<Output = Self> {
+    /// Absolute value of the number.
+    ///
+    /// For integers, the absolute value of `MIN` overflows: it panics in debug
+    /// builds and returns `MIN` in release builds, like the primitive `abs`.
+    fn abs(self) -> Self;
+
+    /// Sign of the number.
+    ///
+    /// For integers, it's `-1`, `0` or `1`. For floats it follows IEEE 754 and
+    /// is never zero: `1.0` for `+0.0` and positive numbers, `-1.0` for `-0.0`
+    /// and negative numbers, and NaN for NaN.
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Signed for $t {
+                #[inline]
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                #[inline]
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// A primitive integer, with the arithmetic that handles overflow explicitly.
+pub trait Integer: Copy {
+    /// Add two numbers, clamping at the bounds of the type instead of overflowing.
+    fn saturating_add(self, other: Self) -> Self;
+    /// Subtract two numbers, clamping at the bounds of the type instead of overflowing.
+    fn saturating_sub(self, other: Self) -> Self;
+    /// Add two numbers, wrapping around at the bounds of the type.
+    fn wrapping_add(self, other: Self) -> Self;
+    /// Subtract two numbers, wrapping around at the bounds of the type.
+    fn wrapping_sub(self, other: Self) -> Self;
+    /// Add two numbers, returning `None` on overflow.
+    fn checked_add(self, other: Self) -> Option<Self>;
+    /// Raise the number to a power, overflowing like the primitive `pow`.
+    fn pow(self, exp: u32) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                #[inline]
+                fn saturating_add(self, other: Self) -> Self {
+                    <$t>::saturating_add(self, other)
+                }
+
+                #[inline]
+                fn saturating_sub(self, other: Self) -> Self {
+                    <$t>::saturating_sub(self, other)
+                }
+
+                #[inline]
+                fn wrapping_add(self, other: Self) -> Self {
+                    <$t>::wrapping_add(self, other)
+                }
+
+                #[inline]
+                fn wrapping_sub(self, other: Self) -> Self {
+                    <$t>::wrapping_sub(self, other)
+                }
+
+                #[inline]
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                #[inline]
+                fn pow(self, exp: u32) -> Self {
+                    <$t>::pow(self, exp)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// A floating point number.
+///
+/// This trait is implemented for `f32` and `f64` and give access to the
+/// floating point functions needed by the isochro lib.
+///
+/// The functions come from `std`, or from the `libm` crate when the `std`
+/// feature is disabled and the `libm` feature enabled. Without either of them,
+/// no type implement `Float`.
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Zero
+    + One
+    + Signed
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The difference between `1.0` and the next larger representable number.
+    const EPSILON: Self;
+
+    /// Square root of the number.
+    fn sqrt(self) -> Self;
+    /// The number raised to a floating point power.
+    fn powf(self, n: Self) -> Self;
+    /// The number raised to an integer power.
+    fn powi(self, n: i32) -> Self;
+    /// Exponential of the number, `e^self`.
+    fn exp(self) -> Self;
+    /// Natural logarithm of the number.
+    fn ln(self) -> Self;
+    /// Arccosine of the number, in radians.
+    fn acos(self) -> Self;
+    /// Sine of the number, in radians.
+    fn sin(self) -> Self;
+    /// Cosine of the number, in radians.
+    fn cos(self) -> Self;
+    /// Tangent of the number, in radians.
+    fn tan(self) -> Self;
+    /// Largest integer lower or equal to the number.
+    fn floor(self) -> Self;
+    /// Smallest integer greater or equal to the number.
+    fn ceil(self) -> Self;
+    /// Nearest integer to the number, rounding half-way cases away from zero.
+    fn round(self) -> Self;
+    /// Integer part of the number, rounding toward zero.
+    fn trunc(self) -> Self;
+    /// Fused multiply-add `self * a + b`, computed with a single rounding.
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    /// The magnitude of the number with the sign of `sign`, including the sign
+    /// of zeros and NaNs.
+    fn copysign(self, sign: Self) -> Self;
+    /// Check that the number is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+    /// Check that the number is NaN.
+    fn is_nan(self) -> bool;
+    /// Check that the number is neither zero, infinite, subnormal nor NaN.
+    fn is_normal(self) -> bool;
+
+    /// Reciprocal of the number, `1 / self`.
+    #[inline]
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+
+    /// Fractional part of the number, `self - self.trunc()`.
+    #[inline]
+    fn fract(self) -> Self {
+        self - self.trunc()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+macro_rules! impl_float {
+    ($t:ty { $($method:ident($($arg:ident: $arg_ty:ty),*) => $f:expr),* $(,)? }) => {
+        impl Float for $t {
+            const EPSILON: Self = <$t>::EPSILON;
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                <$t>::is_finite(self)
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                <$t>::is_nan(self)
+            }
+
+            #[inline]
+            fn is_normal(self) -> bool {
+                <$t>::is_normal(self)
+            }
+
+            $(
+                #[inline]
+                fn $method(self, $($arg: $arg_ty),*) -> Self {
+                    $f(self, $($arg),*)
+                }
+            )*
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl_float!(f32 {
+    sqrt() => f32::sqrt,
+    powf(n: Self) => f32::powf,
+    powi(n: i32) => f32::powi,
+    exp() => f32::exp,
+    ln() => f32::ln,
+    acos() => f32::acos,
+    sin() => f32::sin,
+    cos() => f32::cos,
+    tan() => f32::tan,
+    floor() => f32::floor,
+    ceil() => f32::ceil,
+    round() => f32::round,
+    trunc() => f32::trunc,
+    mul_add(a: Self, b: Self) => f32::mul_add,
+    copysign(sign: Self) => f32::copysign,
+});
+#[cfg(feature = "std")]
+impl_float!(f64 {
+    sqrt() => f64::sqrt,
+    powf(n: Self) => f64::powf,
+    powi(n: i32) => f64::powi,
+    exp() => f64::exp,
+    ln() => f64::ln,
+    acos() => f64::acos,
+    sin() => f64::sin,
+    cos() => f64::cos,
+    tan() => f64::tan,
+    floor() => f64::floor,
+    ceil() => f64::ceil,
+    round() => f64::round,
+    trunc() => f64::trunc,
+    mul_add(a: Self, b: Self) => f64::mul_add,
+    copysign(sign: Self) => f64::copysign,
+});
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl_float!(f32 {
+    sqrt() => libm::sqrtf,
+    powf(n: Self) => libm::powf,
+    powi(n: i32) => |x, n| libm::powf(x, n as f32),
+    exp() => libm::expf,
+    ln() => libm::logf,
+    acos() => libm::acosf,
+    sin() => libm::sinf,
+    cos() => libm::cosf,
+    tan() => libm::tanf,
+    floor() => libm::floorf,
+    ceil() => libm::ceilf,
+    round() => libm::roundf,
+    trunc() => libm::truncf,
+    mul_add(a: Self, b: Self) => libm::fmaf,
+    copysign(sign: Self) => libm::copysignf,
+});
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl_float!(f64 {
+    sqrt() => libm::sqrt,
+    powf(n: Self) => libm::pow,
+    powi(n: i32) => |x, n| libm::pow(x, n as f64),
+    exp() => libm::exp,
+    ln() => libm::log,
+    acos() => libm::acos,
+    sin() => libm::sin,
+    cos() => libm::cos,
+    tan() => libm::tan,
+    floor() => libm::floor,
+    ceil() => libm::ceil,
+    round() => libm::round,
+    trunc() => libm::trunc,
+    mul_add(a: Self, b: Self) => libm::fma,
+    copysign(sign: Self) => libm::copysign,
+});
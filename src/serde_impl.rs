@@ -0,0 +1,149 @@
+//! Serde support, enabled by the `serde` feature.
+//!
+//! A `Vec<D, T>` is serialized as a sequence of its `D` components, and a
+//! `Mat<M, N, T>` as a sequence of its `M` rows, each row being a vector.
+//! For example a 2x2 matrix is written `[[1, 2], [3, 4]]` in JSON.
+//!
+//! Deserializing a sequence of the wrong length returns an error.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+use crate::matrix::Mat;
+use crate::vector::Vec;
+
+/// Read exactly `D` elements from a sequence.
+fn next_array<'de, A, T, const D: usize>(mut seq: A) -> Result<[T; D], A::Error>
+where
+    A: SeqAccess<'de>,
+    T: Deserialize<'de>,
+{
+    let mut error = None;
+    let items: [Option<T>; D] = core::array::from_fn(|i| {
+        if error.is_some() {
+            return None;
+        }
+        match seq.next_element() {
+            Ok(Some(item)) => Some(item),
+            Ok(None) => {
+                error = Some(A::Error::invalid_length(i, &ExpectedLength(D)));
+                None
+            }
+            Err(e) => {
+                error = Some(e);
+                None
+            }
+        }
+    });
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+    if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+        return Err(A::Error::invalid_length(D + 1, &ExpectedLength(D)));
+    }
+    Ok(items.map(|item| item.expect("every item is checked above")))
+}
+
+struct ExpectedLength(usize);
+
+impl serde::de::Expected for ExpectedLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of length {}", self.0)
+    }
+}
+
+impl<T: Serialize, const D: usize> Serialize for Vec<D, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(D)?;
+        for x in &self.0 {
+            tuple.serialize_element(x)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const D: usize> Deserialize<'de> for Vec<D, T> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        struct VecVisitor<T, const D: usize>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>, const D: usize> Visitor<'de> for VecVisitor<T, D> {
+            type Value = Vec<D, T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence of length {D}")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+                next_array(seq).map(Vec)
+            }
+        }
+
+        deserializer.deserialize_tuple(D, VecVisitor(PhantomData))
+    }
+}
+
+impl<T: Serialize, const M: usize, const N: usize> Serialize for Mat<M, N, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(M)?;
+        for row in &self.0 {
+            tuple.serialize_element(row)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const M: usize, const N: usize> Deserialize<'de> for Mat<M, N, T> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        struct MatVisitor<T, const M: usize, const N: usize>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>, const M: usize, const N: usize> Visitor<'de>
+            for MatVisitor<T, M, N>
+        {
+            type Value = Mat<M, N, T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence of {M} rows of length {N}")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+                next_array(seq).map(Mat)
+            }
+        }
+
+        deserializer.deserialize_tuple(M, MatVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Mat;
+    use crate::vector::{Vec2, Vec3};
+
+    #[test]
+    fn test_vec_round_trip() {
+        let vec = Vec3::new(1, 2, 3);
+        let json = serde_json::to_string(&vec).unwrap();
+        assert_eq!(json, "[1,2,3]");
+        assert_eq!(serde_json::from_str::<Vec3<i32>>(&json).unwrap(), vec);
+    }
+
+    #[test]
+    fn test_vec_wrong_length() {
+        assert!(serde_json::from_str::<Vec3<i32>>("[1,2]").is_err());
+        assert!(serde_json::from_str::<Vec3<i32>>("[1,2,3,4]").is_err());
+    }
+
+    #[test]
+    fn test_mat_round_trip() {
+        let mat = Mat([Vec2::new(1, 2), Vec2::new(3, 4)]);
+        let json = serde_json::to_string(&mat).unwrap();
+        assert_eq!(json, "[[1,2],[3,4]]");
+        assert_eq!(serde_json::from_str::<Mat<2, 2, i32>>(&json).unwrap(), mat);
+        assert!(serde_json::from_str::<Mat<2, 2, i32>>("[[1,2],[3]]").is_err());
+        assert!(serde_json::from_str::<Mat<2, 2, i32>>("[[1,2]]").is_err());
+    }
+}
@@ -15,24 +15,71 @@
 //! assert_eq!(c.z, 9.0);
 //! ```
 
+mod bitwise;
+mod simd;
 mod vec2;
 mod vec3;
 mod vec4;
 
-use std::iter::zip;
-use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
+use core::fmt;
+use core::iter::zip;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub,
+    SubAssign,
+};
 
+/// The dot product trait, moved to [`crate::ops::DotProduct`].
+///
+/// This re-export is kept for compatibility and will be removed in the next
+/// release, import the trait from `isochro::ops` instead.
+#[deprecated(since = "0.1.0", note = "use `isochro::ops::DotProduct` instead")]
+pub use crate::ops::DotProduct;
 pub use vec2::*;
 pub use vec3::*;
 pub use vec4::*;
 
 use crate::macros::forward_ref_binop;
-use crate::ops::DotProduct;
+use crate::scalar::{Float, Integer, One, Signed, Zero};
+use simd::{SimdAdd, SimdDot, SimdMulScalar, SimdSub};
 
 /// A generic vector type with compile-time dimensionality.
-#[derive(Clone, Copy, Debug, PartialEq)]
+///
+/// `Eq` and `Hash` are only implemented when the element type implements them,
+/// so integer vectors can be used as map keys while float vectors can't.
+///
+/// `PartialOrd` and `Ord` compare the vectors lexicographically, component by
+/// component like arrays, so `(0, 9) < (1, 0)`. This is an arbitrary total order
+/// for sorting and ordered collections, NOT a component-wise order: use
+/// [`Vec::cmplt`] and the other `cmp*` methods for component-wise comparisons.
+/// Note that the inherent [`Vec::min`], [`Vec::max`] and [`Vec::clamp`] are
+/// component-wise, the lexicographic ones are reached with `Ord::min(a, b)`.
+///
+/// # Example
+/// ```
+/// use isochro::vector::Vec2;
+/// let a = Vec2::new(0, 9);
+/// let b = Vec2::new(1, 0);
+/// assert!(a < b);
+/// assert!(!a.cmplt(b).all());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
 pub struct Vec<const DIM: usize, T>(pub [T; DIM]);
 
+/// A struct giving a name to each component of a `Vec<D, T>`, see [`Vec::window`].
+///
+/// `Vec2`, `Vec3` and `Vec4` deref to their window so the components can be
+/// accessed as `v.x`. Windows for other dimensions are declared with the
+/// [`window!`](crate::window) macro, which implement this trait.
+///
+/// # Safety
+/// The type must have the layout of `[T; D]`: `#[repr(C)]` with `D` fields of type `T`.
+pub unsafe trait Window<const D: usize, T> {
+    /// Compile-time checks of the layout, evaluated on each cast to the window.
+    #[doc(hidden)]
+    const LAYOUT_CHECK: () = ();
+}
+
 //generic case
 impl<T, const D: usize> Index<usize> for Vec<D, T> {
     type Output = T;
@@ -54,154 +101,1743 @@ impl<T, const D: usize> From<[T; D]> for Vec<D, T> {
     fn from(x: [T; D]) -> Self {
         Self(x)
     }
-}
+}
+
+impl<T, const D: usize> From<Vec<D, T>> for [T; D] {
+    fn from(x: Vec<D, T>) -> Self {
+        x.0
+    }
+}
+
+impl<T: fmt::Display, const D: usize> fmt::Display for Vec<D, T> {
+    /// Format the vector as a tuple, the formatting options are applied to each component.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let a = Vec3::new(1.0, 2.5, 3.125);
+    /// assert_eq!(format!("{a}"), "(1, 2.5, 3.125)");
+    /// assert_eq!(format!("{a:.2}"), "(1.00, 2.50, 3.12)");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        for (i, x) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            x.fmt(f)?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl<T: Copy, const D: usize> TryFrom<&[T]> for Vec<D, T> {
+    type Error = core::array::TryFromSliceError;
+
+    /// Create a vector from a slice, failing if its length isn't `D`.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec;
+    /// let data = [1, 2, 3, 4];
+    /// let a = Vec::<3, _>::try_from(&data[..3]);
+    /// assert_eq!(a.unwrap(), (1, 2, 3));
+    /// assert!(Vec::<3, _>::try_from(&data[..]).is_err());
+    /// ```
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        <[T; D]>::try_from(slice).map(Self)
+    }
+}
+
+impl<T: Default, const D: usize> Default for Vec<D, T> {
+    /// Create a vector with every component set to its default value.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// assert_eq!(Vec3::<f32>::default(), Vec3::new(0.0, 0.0, 0.0));
+    /// ```
+    fn default() -> Self {
+        Self(core::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T, const D: usize> IntoIterator for Vec<D, T> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, D>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, const D: usize> IntoIterator for &'a Vec<D, T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T, const D: usize> IntoIterator for &'a mut Vec<D, T> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<T, const D: usize> Vec<D, T> {
+    /// View the components of the vector as a slice.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let a = Vec3::new(1, 2, 3);
+    /// assert_eq!(a.as_slice().len(), 3);
+    /// assert_eq!(a.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// View the components of the vector as a mutable slice.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let mut a = Vec3::new(1, 2, 3);
+    /// a.as_mut_slice()[1] = 5;
+    /// assert_eq!(a, (1, 5, 3));
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+
+    /// View the components of the vector through a [`Window`], to access them by name.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec;
+    /// isochro::window! {
+    ///     /// A color with an alpha channel.
+    ///     pub struct Rgba<4> { r, g, b, a }
+    /// }
+    ///
+    /// let color = Vec([255, 128, 0, 255]);
+    /// let rgba: &Rgba<u8> = color.window();
+    /// assert_eq!(rgba.g, 128);
+    /// ```
+    pub fn window<W: Window<D, T>>(&self) -> &W {
+        let () = const {
+            assert!(size_of::<W>() == size_of::<[T; D]>());
+            assert!(align_of::<W>() == align_of::<[T; D]>());
+        };
+        let () = W::LAYOUT_CHECK;
+        // SAFETY: `Vec` is `#[repr(transparent)]` and `W` has the layout of `[T; D]`
+        unsafe { &*(self as *const Self).cast::<W>() }
+    }
+
+    /// View the components of the vector mutably through a [`Window`], see [`Vec::window`].
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec;
+    /// isochro::window! {
+    ///     /// The state of a body.
+    ///     pub struct State<6> { px, py, pz, vx, vy, vz }
+    /// }
+    ///
+    /// let mut state = Vec([0.0, 0.0, 0.0, 1.0, 2.0, 3.0]);
+    /// let fields: &mut State<f64> = state.window_mut();
+    /// fields.px += fields.vx;
+    /// assert_eq!(state, Vec([1.0, 0.0, 0.0, 1.0, 2.0, 3.0]));
+    /// ```
+    pub fn window_mut<W: Window<D, T>>(&mut self) -> &mut W {
+        let () = const {
+            assert!(size_of::<W>() == size_of::<[T; D]>());
+            assert!(align_of::<W>() == align_of::<[T; D]>());
+        };
+        let () = W::LAYOUT_CHECK;
+        // SAFETY: `Vec` is `#[repr(transparent)]` and `W` has the layout of `[T; D]`
+        unsafe { &mut *(self as *mut Self).cast::<W>() }
+    }
+
+    /// Get a reference to the component at the given index, or `None` if out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(1, 2);
+    /// assert_eq!(a.get(1), Some(&2));
+    /// assert!(a.get(5).is_none());
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.0.get(index)
+    }
+
+    /// Get a mutable reference to the component at the given index, or `None` if out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let mut a = Vec2::new(1, 2);
+    /// if let Some(y) = a.get_mut(1) {
+    ///     *y = 5;
+    /// }
+    /// assert_eq!(a, (1, 5));
+    /// assert!(a.get_mut(2).is_none());
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.0.get_mut(index)
+    }
+
+    /// Iterate over the components of the vector.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let a = Vec3::new(1, 2, 3);
+    /// assert_eq!(a.iter().sum::<i32>(), 6);
+    /// ```
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.0.iter()
+    }
+
+    /// Iterate mutably over the components of the vector.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let mut a = Vec3::new(1, 2, 3);
+    /// for x in a.iter_mut() {
+    ///     *x *= 2;
+    /// }
+    /// assert_eq!(a, (2, 4, 6));
+    /// ```
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.0.iter_mut()
+    }
+
+    /// Create a vector with every component set to `value`.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec4;
+    /// assert_eq!(Vec4::splat(7), (7, 7, 7, 7));
+    ///
+    /// const ONES: Vec4<f32> = Vec4::splat(1.0);
+    /// assert_eq!(ONES.w, 1.0);
+    /// ```
+    pub const fn splat(value: T) -> Self
+    where
+        T: Copy,
+    {
+        Self([value; D])
+    }
+
+    /// Create a vector from the first `D` items of an iterator.
+    ///
+    /// Return `None` if the iterator yields fewer than `D` items, extra items are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec;
+    /// let a = Vec::<3, i32>::try_from_iter(0..5);
+    /// assert_eq!(a, Some(Vec([0, 1, 2])));
+    /// let b = Vec::<3, i32>::try_from_iter(0..2);
+    /// assert_eq!(b, None);
+    /// ```
+    pub fn try_from_iter(iter: impl IntoIterator<Item = T>) -> Option<Self> {
+        let mut iter = iter.into_iter();
+        let items: [Option<T>; D] = core::array::from_fn(|_| iter.next());
+        if items.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(Vec(items.map(|x| x.expect("checked above"))))
+    }
+}
+
+// heart of most of the operations on Vec
+// TODO: Find faster way in debug mode to merge two statics arrays
+impl<T, const D: usize> Vec<D, T> {
+    /// Create a new vector from two other vectors and a combining function.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(1, 2);
+    /// let b = Vec2::new(3, 4);
+    /// let c = Vec2::combine(a, b, |a, b| a.min(b));
+    /// assert_eq!(c.x, 1);
+    /// assert_eq!(c.y, 2);
+    /// ```
+    pub fn combine<U, R>(self, other: Vec<D, U>, f: impl Fn(T, U) -> R) -> Vec<D, R> {
+        let a = self.0.into_iter();
+        let b = other.0.into_iter();
+        let mut iter = zip(a, b).map(|(a, b)| f(a, b));
+
+        Vec(core::array::from_fn(|_| {
+            iter.next().expect("the vectors have D components")
+        }))
+    }
+
+    /// Create a new vector from three other vectors and a combining function.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let a = Vec3::new(1.5_f64, 2.0, -1.0);
+    /// let b = Vec3::new(2, 3, 4);
+    /// let c = Vec3::new(0.5, 0.0, 1.0);
+    /// let d = a.combine3(b, c, |a, b, c| a.mul_add(f64::from(b), c));
+    /// assert_eq!(d, (3.5, 6.0, -3.0));
+    /// ```
+    pub fn combine3<U, V, R>(
+        self,
+        b: Vec<D, U>,
+        c: Vec<D, V>,
+        f: impl Fn(T, U, V) -> R,
+    ) -> Vec<D, R> {
+        let mut iter = zip(zip(self.0, b.0), c.0).map(|((a, b), c)| f(a, b, c));
+
+        Vec(core::array::from_fn(|_| {
+            iter.next().expect("the vectors have D components")
+        }))
+    }
+
+    /// Create a new vector from a scalar and a combining function.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(1, 2);
+    /// let b = Vec2::combine_scalar(a, 2, |a, b| a * b);
+    /// assert_eq!(b.x, 2);
+    /// assert_eq!(b.y, 4);
+    /// ```
+    pub fn combine_scalar<U: Clone, R>(self, other: U, f: impl Fn(T, U) -> R) -> Vec<D, R> {
+        Vec(self.0.map(|a| f(a, other.clone())))
+    }
+
+    /// Create a new vector from two other vectors and a combining function.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(1, 2);
+    /// let b = Vec2::new(3, 4);
+    /// let c = Vec2::combine_ref(a, &b, |a, b| a.min(*b)); // b is a reference
+    /// assert_eq!(c.x, 1);
+    /// assert_eq!(c.y, 2);
+    /// ```
+    pub fn combine_ref<U, R>(self, other: &Vec<D, U>, f: impl Fn(T, &U) -> R) -> Vec<D, R> {
+        let a = self.0.into_iter();
+        let b = other.0.iter();
+        let mut iter = zip(a, b).map(|(a, b)| f(a, b));
+
+        Vec(core::array::from_fn(|_| {
+            iter.next().expect("the vectors have D components")
+        }))
+    }
+
+    /// Create a new vector from a scalar and a combining function.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(1, 2);
+    /// let b = Vec2::combine_scalar_ref(&a, &2, |a, b| a * *b);
+    /// assert_eq!(b.x, 2);
+    /// assert_eq!(b.y, 4);
+    /// ```
+    pub fn combine_scalar_ref<U: Copy, R>(&self, other: U, f: impl Fn(&T, U) -> R) -> Vec<D, R> {
+        Vec(self.0.each_ref().map(|a| f(a, other)))
+    }
+
+    /// Create a new vector from two other vectors and a combining function.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(1, 2);
+    /// let b = Vec2::new(3, 4);
+    /// let c = Vec2::combine_both_ref(&a, &b, |a, b| *a.min(b)); // a and b are references
+    /// assert_eq!(c, (1, 2));
+    /// ```
+    pub fn combine_both_ref<U, R>(&self, other: &Vec<D, U>, f: impl Fn(&T, &U) -> R) -> Vec<D, R> {
+        let a = self.0.iter();
+        let b = other.0.iter();
+        let mut iter = zip(a, b).map(|(a, b)| f(a, b));
+
+        Vec(core::array::from_fn(|_| {
+            iter.next().expect("the vectors have D components")
+        }))
+    }
+
+    /// Create a new vector from two other vectors and a combining function.
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let mut a = Vec2::new(1, 2);
+    /// let b = Vec2::new(3, 4);
+    /// a.combine_assign(b, |mut a, b| *a += b);
+    /// assert_eq!(a.x, 4);
+    /// assert_eq!(a.y, 6);
+    /// ```
+    pub fn combine_assign<U>(&mut self, other: Vec<D, U>, f: impl Fn(&mut T, U)) {
+        for (a, b) in zip(self.0.iter_mut(), other.0) {
+            f(a, b);
+        }
+    }
+
+    /// Create a new vector from a scalar and a combining function.
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let mut a = Vec2::new(1, 2);
+    /// a.combine_assign_scalar(2, |a, b| *a = *a * b);
+    /// assert_eq!(a.x, 2);
+    /// assert_eq!(a.y, 4);
+    /// ```
+    pub fn combine_assign_scalar<U: Clone>(&mut self, other: U, f: impl Fn(&mut T, U)) {
+        for a in self.0.iter_mut() {
+            f(a, other.clone());
+        }
+    }
+
+    /// Create a new vector from two other vectors and a combining function.
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let mut a = Vec2::new(1, 2);
+    /// let b = Vec2::new(3, 4);
+    /// a.combine_assign_ref(&b, |mut a, b| *a += b);
+    /// assert_eq!(a.x, 4);
+    /// assert_eq!(a.y, 6);
+    /// ```
+    pub fn combine_assign_ref<U>(&mut self, other: &Vec<D, U>, f: impl Fn(&mut T, &U)) {
+        for (a, b) in zip(self.0.iter_mut(), &other.0) {
+            f(a, b);
+        }
+    }
+
+    /// Create a new vector by applying a function to each component.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let a = Vec3::new(1i32, 2, 3);
+    /// let b = a.map(|x| x as f64);
+    /// assert_eq!(b, (1.0, 2.0, 3.0));
+    /// ```
+    pub fn map<R>(self, f: impl Fn(T) -> R) -> Vec<D, R> {
+        Vec(self.0.map(f))
+    }
+
+    /// Convert each component to another type, without loss.
+    ///
+    /// This only allows the conversions implemented with `From`, like `i32` to
+    /// `f64` or `u8` to `u32`. See `as_cast` (`num-traits` feature) for the lossy
+    /// conversions done by `as`.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(1i32, 2);
+    /// let b = a.cast::<f64>();
+    /// assert_eq!(b, (1.0, 2.0));
+    /// ```
+    pub fn cast<R>(self) -> Vec<D, R>
+    where
+        T: Into<R>,
+    {
+        self.map(T::into)
+    }
+
+    /// Convert each component to another primitive type with the `as` operator.
+    ///
+    /// The conversion can lose information: floats are truncated toward zero and
+    /// saturate when converted to integers, integers wrap when converted to a
+    /// smaller integer type.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(1.7f32, -300.0);
+    /// let b = a.as_cast::<i8>();
+    /// assert_eq!(b, (1, -128));
+    /// ```
+    #[cfg(feature = "num-traits")]
+    pub fn as_cast<R>(self) -> Vec<D, R>
+    where
+        T: num_traits::AsPrimitive<R>,
+        R: Copy + 'static,
+    {
+        self.map(T::as_)
+    }
+
+    /// Create a new vector by applying a function to a reference of each component.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(String::from("a"), String::from("bc"));
+    /// let b = a.map_ref(|s| s.len());
+    /// assert_eq!(b, (1, 2));
+    /// ```
+    pub fn map_ref<R>(&self, f: impl Fn(&T) -> R) -> Vec<D, R> {
+        Vec(self.0.each_ref().map(f))
+    }
+
+    /// Reorder the components, the component `i` of the result being the
+    /// component `indices[i]` of the vector. An index can be repeated or left out.
+    ///
+    /// # Panics
+    /// Panics if an index is out of bounds, like indexing the vector.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::{Vec3, Vec4};
+    /// assert_eq!(Vec4::new(1, 2, 3, 4).permute([3, 2, 1, 0]), (4, 3, 2, 1));
+    /// // swap the y and z axes
+    /// assert_eq!(Vec3::new(1, 2, 3).permute([0, 2, 1]), (1, 3, 2));
+    /// ```
+    pub fn permute(self, indices: [usize; D]) -> Vec<D, T>
+    where
+        T: Copy,
+    {
+        Vec(indices.map(|i| self[i]))
+    }
+}
+
+impl<T, const D: usize> Vec<D, T> {
+    /// Multiply two vectors component by component.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(2, 3);
+    /// let b = Vec2::new(4, 5);
+    /// assert_eq!(a.component_mul(b), (8, 15));
+    /// ```
+    pub fn component_mul<U, R>(self, other: Vec<D, U>) -> Vec<D, R>
+    where
+        T: Mul<U, Output = R>,
+    {
+        self.combine(other, T::mul)
+    }
+
+    /// Divide two vectors component by component.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(8, 15);
+    /// let b = Vec2::new(4, 5);
+    /// assert_eq!(a.component_div(b), (2, 3));
+    /// ```
+    pub fn component_div<U, R>(self, other: Vec<D, U>) -> Vec<D, R>
+    where
+        T: Div<U, Output = R>,
+    {
+        self.combine(other, T::div)
+    }
+
+    /// Get the remainder of the division of two vectors component by component.
+    ///
+    /// Like the `%` operator, the result of an integer remainder has the sign of
+    /// the dividend.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(8, -7);
+    /// let b = Vec2::new(3, 5);
+    /// assert_eq!(a.component_rem(b), (2, -2));
+    /// ```
+    pub fn component_rem<U, R>(self, other: Vec<D, U>) -> Vec<D, R>
+    where
+        T: Rem<U, Output = R>,
+    {
+        self.combine(other, T::rem)
+    }
+}
+
+impl<T: Integer, const D: usize> Vec<D, T> {
+    /// Add two vectors, clamping each component at the bounds of the type.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec4;
+    /// let color = Vec4::<u8>::new(200, 100, 0, 255);
+    /// let light = Vec4::new(100, 100, 100, 0);
+    /// assert_eq!(color.saturating_add(light), (255, 200, 100, 255));
+    /// ```
+    pub fn saturating_add(self, other: Vec<D, T>) -> Vec<D, T> {
+        self.combine(other, T::saturating_add)
+    }
+
+    /// Subtract two vectors, clamping each component at the bounds of the type.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::<u8>::new(10, 200);
+    /// assert_eq!(a.saturating_sub(Vec2::new(20, 20)), (0, 180));
+    /// ```
+    pub fn saturating_sub(self, other: Vec<D, T>) -> Vec<D, T> {
+        self.combine(other, T::saturating_sub)
+    }
+
+    /// Add two vectors, wrapping each component around at the bounds of the type.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::<u8>::new(250, 1);
+    /// assert_eq!(a.wrapping_add(Vec2::new(10, 1)), (4, 2));
+    /// ```
+    pub fn wrapping_add(self, other: Vec<D, T>) -> Vec<D, T> {
+        self.combine(other, T::wrapping_add)
+    }
+
+    /// Subtract two vectors, wrapping each component around at the bounds of the type.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::<u8>::new(5, 1);
+    /// assert_eq!(a.wrapping_sub(Vec2::new(10, 1)), (251, 0));
+    /// ```
+    pub fn wrapping_sub(self, other: Vec<D, T>) -> Vec<D, T> {
+        self.combine(other, T::wrapping_sub)
+    }
+
+    /// Add two vectors, returning `None` if any component overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::<u8>::new(250, 1);
+    /// assert_eq!(a.checked_add(Vec2::new(5, 1)), Some(Vec2::new(255, 2)));
+    /// assert_eq!(a.checked_add(Vec2::new(6, 1)), None);
+    /// ```
+    pub fn checked_add(self, other: Vec<D, T>) -> Option<Vec<D, T>> {
+        Vec::try_from_iter(zip(self.0, other.0).map_while(|(a, b)| a.checked_add(b)))
+    }
+
+    /// Raise each component to the power `exp`.
+    ///
+    /// An overflow panics in debug builds and wraps in release builds, like
+    /// the primitive `pow`. Float vectors have [`Vec::powi`] instead.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// assert_eq!(Vec3::new(2, -3, 10).pow(3), (8, -27, 1000));
+    /// ```
+    pub fn pow(self, exp: u32) -> Vec<D, T> {
+        self.map(|x| x.pow(exp))
+    }
+}
+
+impl<T: Signed, const D: usize> Vec<D, T> {
+    /// Get the absolute value of each component.
+    ///
+    /// For integers, the absolute value of `MIN` overflows, see [`Signed::abs`].
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// assert_eq!(Vec2::new(-3, 4).abs(), (3, 4));
+    /// ```
+    pub fn abs(self) -> Vec<D, T> {
+        self.map(T::abs)
+    }
+
+    /// Get the sign of each component, see [`Signed::signum`]: `-1`, `0` or `1`
+    /// for integers, while floats are never zero and keep the sign of zeros.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec3;
+    /// assert_eq!(Vec3::new(-5, 0, 3).signum(), (-1, 0, 1));
+    /// assert_eq!(Vec3::new(-5.0, 0.0, 3.0).signum(), (-1.0, 1.0, 1.0));
+    /// assert_eq!(Vec3::new(-0.0, f64::NEG_INFINITY, 0.5).signum(), (-1.0, -1.0, 1.0));
+    /// assert!(Vec3::new(f64::NAN, 1.0, 1.0).signum().is_nan());
+    /// # }
+    /// ```
+    pub fn signum(self) -> Vec<D, T> {
+        self.map(T::signum)
+    }
+}
+
+impl<T: PartialEq, const D: usize> Vec<D, T> {
+    /// Compare the vectors component-wise with `==`.
+    ///
+    /// The comparison methods are named `cmp*` rather than `eq`, `lt`... so they
+    /// don't shadow the `PartialEq` and `PartialOrd` methods, which compare whole vectors.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let mask = Vec3::new(1, 5, 3).cmpeq(Vec3::new(2, 2, 3));
+    /// assert_eq!(mask, Vec3::from([false, false, true]));
+    /// ```
+    pub fn cmpeq(self, other: Vec<D, T>) -> Vec<D, bool> {
+        self.combine(other, |a, b| a == b)
+    }
+
+    /// Compare the vectors component-wise with `!=`.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let mask = Vec3::new(1, 5, 3).cmpne(Vec3::new(2, 2, 3));
+    /// assert_eq!(mask, Vec3::from([true, true, false]));
+    /// ```
+    pub fn cmpne(self, other: Vec<D, T>) -> Vec<D, bool> {
+        self.combine(other, |a, b| a != b)
+    }
+
+    /// Compare the vectors component-wise with `==`, the same as [`Vec::cmpeq`].
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let mask = Vec3::new(1, 5, 3).eq_elem(Vec3::new(2, 2, 3));
+    /// assert_eq!(mask, Vec3::from([false, false, true]));
+    /// ```
+    pub fn eq_elem(self, other: Vec<D, T>) -> Vec<D, bool> {
+        self.cmpeq(other)
+    }
+}
+
+impl<T: PartialOrd, const D: usize> Vec<D, T> {
+    /// Compare the vectors component-wise with `<`.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let mask = Vec3::new(1, 5, 3).cmplt(Vec3::new(2, 2, 2));
+    /// assert_eq!(mask, Vec3::from([true, false, false]));
+    /// ```
+    pub fn cmplt(self, other: Vec<D, T>) -> Vec<D, bool> {
+        self.combine(other, |a, b| a < b)
+    }
+
+    /// Compare the vectors component-wise with `<=`.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let mask = Vec3::new(1, 5, 3).cmple(Vec3::new(2, 2, 3));
+    /// assert_eq!(mask, (true, false, true));
+    /// ```
+    pub fn cmple(self, other: Vec<D, T>) -> Vec<D, bool> {
+        self.combine(other, |a, b| a <= b)
+    }
+
+    /// Compare the vectors component-wise with `>`.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let mask = Vec3::new(1, 5, 3).cmpgt(Vec3::new(2, 2, 3));
+    /// assert_eq!(mask, (false, true, false));
+    /// ```
+    pub fn cmpgt(self, other: Vec<D, T>) -> Vec<D, bool> {
+        self.combine(other, |a, b| a > b)
+    }
+
+    /// Compare the vectors component-wise with `>=`.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let mask = Vec3::new(1, 5, 3).cmpge(Vec3::new(2, 2, 3));
+    /// assert_eq!(mask, (false, true, true));
+    /// ```
+    pub fn cmpge(self, other: Vec<D, T>) -> Vec<D, bool> {
+        self.combine(other, |a, b| a >= b)
+    }
+}
+
+impl<const D: usize> Vec<D, bool> {
+    /// Check if every component of the mask is `true`, `true` for an empty mask.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let v = Vec3::new(1, 2, 3);
+    /// assert!(v.cmplt(Vec3::splat(4)).all());
+    /// assert!(!v.cmplt(Vec3::splat(2)).all());
+    /// ```
+    pub fn all(self) -> bool {
+        self.0.iter().all(|&x| x)
+    }
+
+    /// Check if any component of the mask is `true`, `false` for an empty mask.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let v = Vec3::new(1, 2, 3);
+    /// assert!(v.cmplt(Vec3::splat(4)).any());
+    /// assert!(v.cmplt(Vec3::splat(2)).any());
+    /// assert!(!v.cmplt(Vec3::splat(1)).any());
+    /// ```
+    pub fn any(self) -> bool {
+        self.0.iter().any(|&x| x)
+    }
+
+    /// Count the `true` components of the mask.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// assert_eq!(Vec3::new(true, false, true).count_true(), 2);
+    /// assert_eq!(Vec3::splat(true).count_true(), 3);
+    /// ```
+    pub fn count_true(self) -> usize {
+        self.0.iter().filter(|&&x| x).count()
+    }
+
+    /// Choose each component from `a` where the mask is `true`, and from `b` otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let mask = Vec2::new(true, false);
+    /// assert_eq!(mask.select(Vec2::new(1, 2), Vec2::new(3, 4)), (1, 4));
+    ///
+    /// // a branchless component-wise maximum
+    /// let (a, b) = (Vec2::new(1, 5), Vec2::new(3, 2));
+    /// assert_eq!(a.cmpgt(b).select(a, b), (3, 5));
+    /// ```
+    pub fn select<T: Copy>(self, a: Vec<D, T>, b: Vec<D, T>) -> Vec<D, T> {
+        Vec(core::array::from_fn(|i| {
+            if self.0[i] { a.0[i] } else { b.0[i] }
+        }))
+    }
+}
+
+impl<T: PartialOrd + Copy, const D: usize> Vec<D, T> {
+    /// Get the component-wise minimum of two vectors.
+    ///
+    /// NaNs are not propagated: when a pair of components can't be compared,
+    /// the component of `self` is kept.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(1, 5);
+    /// let b = Vec2::new(3, 2);
+    /// assert_eq!(a.min(b), (1, 2));
+    /// ```
+    pub fn min(self, other: Vec<D, T>) -> Vec<D, T> {
+        self.combine(other, |a, b| if b < a { b } else { a })
+    }
+
+    /// Get the component-wise maximum of two vectors.
+    ///
+    /// NaNs are not propagated: when a pair of components can't be compared,
+    /// the component of `self` is kept.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(1, 5);
+    /// let b = Vec2::new(3, 2);
+    /// assert_eq!(a.max(b), (3, 5));
+    /// ```
+    pub fn max(self, other: Vec<D, T>) -> Vec<D, T> {
+        self.combine(other, |a, b| if b > a { b } else { a })
+    }
+
+    /// Clamp each component between the matching components of `lo` and `hi`.
+    ///
+    /// Every component of `lo` must be lower or equal to the one of `hi`,
+    /// this is checked in debug builds.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let a = Vec3::new(-1, 5, 2);
+    /// let b = a.clamp(Vec3::new(0, 0, 0), Vec3::new(4, 4, 4));
+    /// assert_eq!(b, (0, 4, 2));
+    /// ```
+    pub fn clamp(self, lo: Vec<D, T>, hi: Vec<D, T>) -> Vec<D, T> {
+        debug_assert!(
+            zip(lo.0, hi.0).all(|(lo, hi)| lo <= hi),
+            "clamp requires lo <= hi on every component"
+        );
+        self.max(lo).min(hi)
+    }
+
+    /// Clamp each component between `lo` and `hi`.
+    ///
+    /// `lo` must be lower or equal to `hi`, this is checked in debug builds.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let a = Vec3::new(-1, 5, 2);
+    /// assert_eq!(a.clamp_scalar(0, 4), (0, 4, 2));
+    /// ```
+    pub fn clamp_scalar(self, lo: T, hi: T) -> Vec<D, T> {
+        debug_assert!(lo <= hi, "clamp_scalar requires lo <= hi");
+        self.map(|x| {
+            if x < lo {
+                lo
+            } else if x > hi {
+                hi
+            } else {
+                x
+            }
+        })
+    }
+}
+
+impl<T, const D: usize> Vec<D, T> {
+    /// Reduce the components into a single value, starting from `init` and
+    /// calling `f` with the accumulator and each component, from index `0` to
+    /// `D - 1`.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec4;
+    /// let v = Vec4::new(3, 9, -2, 5);
+    /// assert_eq!(v.fold(i32::MIN, i32::max), 9);
+    /// let digits = v.fold(String::new(), |acc, x| acc + &x.to_string());
+    /// assert_eq!(digits, "39-25");
+    /// ```
+    pub fn fold<B>(self, init: B, f: impl FnMut(B, T) -> B) -> B {
+        self.0.into_iter().fold(init, f)
+    }
+
+    /// Reduce the components like [`Vec::fold`], stopping at the first error,
+    /// which is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let sum = |v: Vec3<u8>| v.try_fold(0u8, |acc, x| acc.checked_add(x).ok_or(x));
+    /// assert_eq!(sum(Vec3::new(100, 100, 50)), Ok(250));
+    /// // the component that overflowed
+    /// assert_eq!(sum(Vec3::new(100, 200, 50)), Err(200));
+    /// ```
+    pub fn try_fold<B, E>(self, init: B, f: impl FnMut(B, T) -> Result<B, E>) -> Result<B, E> {
+        self.0.into_iter().try_fold(init, f)
+    }
+
+    /// Sum all the components of the vector.
+    ///
+    /// A zero-dimensional vector sums to zero.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// assert_eq!(Vec3::new(1, 2, 3).sum(), 6);
+    /// ```
+    pub fn sum(self) -> T
+    where
+        T: Add<Output = T> + Zero,
+    {
+        self.fold(T::zero(), T::add)
+    }
+
+    /// Multiply all the components of the vector together.
+    ///
+    /// A zero-dimensional vector has a product of one.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// assert_eq!(Vec3::new(2, 3, 4).product(), 24);
+    /// ```
+    pub fn product(self) -> T
+    where
+        T: Mul<Output = T> + One,
+    {
+        self.fold(T::one(), T::mul)
+    }
+}
+
+impl<T, const D: usize> Vec<D, T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Copy,
+{
+    /// Calculate the squared length of the vector.
+    ///
+    /// This is cheaper than [`Vec::length`] since it doesn't need a square root,
+    /// prefer it when comparing lengths.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(3, 4);
+    /// assert_eq!(a.length_squared(), 25);
+    /// ```
+    pub fn length_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    /// Calculate the squared distance between two points.
+    ///
+    /// Like [`Vec::length_squared`], it avoids the square root of [`Vec::distance`].
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(1, 1);
+    /// let b = Vec2::new(4, 5);
+    /// assert_eq!(a.distance_squared(b), 25);
+    /// ```
+    pub fn distance_squared(self, other: Vec<D, T>) -> T
+    where
+        T: Sub<Output = T>,
+    {
+        (other - self).length_squared()
+    }
+}
+
+impl<T: Float, const D: usize> Vec<D, T> {
+    /// Calculate the length of the vector.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(3.0_f32, 4.0);
+    /// assert_eq!(a.length(), 5.0);
+    /// # }
+    /// ```
+    pub fn length(&self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    /// Calculate the p-norm of the vector, `(|x1|^p + |x2|^p + ...)^(1/p)`.
+    ///
+    /// `p = 1` is the Manhattan norm, the sum of the absolute values, and `p = 2`
+    /// the Euclidean norm, which [`Vec::length`] computes more precisely. See
+    /// [`Vec::norm_inf`] for the limit as `p` grows. `p` is expected to be at
+    /// least 1, below that the result isn't a norm.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(3.0, -4.0);
+    /// assert_eq!(a.norm(1.0), 7.0);
+    /// assert_eq!(a.norm(2.0), 5.0);
+    /// # }
+    /// ```
+    pub fn norm(&self, p: T) -> T {
+        self.abs().powf(p).sum().powf(p.recip())
+    }
+
+    /// Calculate the infinity norm of the vector, the largest absolute value of
+    /// its components. It's zero for a zero-dimensional vector, and NaN if a
+    /// component is NaN, like the other norms.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec3;
+    /// assert_eq!(Vec3::new(3.0, -4.0, 1.0).norm_inf(), 4.0);
+    /// # }
+    /// ```
+    pub fn norm_inf(&self) -> T {
+        self.abs().fold(
+            T::zero(),
+            |max, x| if x > max || x.is_nan() { x } else { max },
+        )
+    }
+
+    /// Calculate the distance between two points.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(1.0_f32, 1.0);
+    /// let b = Vec2::new(4.0, 5.0);
+    /// assert_eq!(a.distance(b), 5.0);
+    /// # }
+    /// ```
+    pub fn distance(self, other: Vec<D, T>) -> T {
+        (other - self).length()
+    }
+
+    /// Linearly interpolate between two vectors.
+    ///
+    /// `t` isn't clamped, values outside of `[0, 1]` extrapolate past `self` or `other`.
+    /// See [`Vec::lerp_clamped`] for the clamped version.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(0.0, 0.0);
+    /// let b = Vec2::new(10.0, 10.0);
+    /// assert_eq!(a.lerp(b, 0.5), (5.0, 5.0));
+    /// assert_eq!(a.lerp(b, 2.0), (20.0, 20.0));
+    /// # }
+    /// ```
+    pub fn lerp(self, other: Vec<D, T>, t: T) -> Vec<D, T> {
+        self + (other - self) * t
+    }
+
+    /// Linearly interpolate between two vectors, with `t` clamped to `[0, 1]`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(0.0, 0.0);
+    /// let b = Vec2::new(10.0, 10.0);
+    /// assert_eq!(a.lerp_clamped(b, 2.0), (10.0, 10.0));
+    /// # }
+    /// ```
+    pub fn lerp_clamped(self, other: Vec<D, T>, t: T) -> Vec<D, T> {
+        let t = if t < T::zero() {
+            T::zero()
+        } else if t > T::one() {
+            T::one()
+        } else {
+            t
+        };
+        self.lerp(other, t)
+    }
+
+    /// Linearly interpolate between two vectors, with a different `t` for each
+    /// component.
+    ///
+    /// Like [`Vec::lerp`], `t` isn't clamped.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(0.0, 0.0);
+    /// let b = Vec2::new(10.0, 10.0);
+    /// assert_eq!(a.lerp(b, 0.5), (5.0, 5.0));
+    /// assert_eq!(a.component_lerp(b, Vec2::new(0.5, 0.1)), (5.0, 1.0));
+    /// # }
+    /// ```
+    pub fn component_lerp(self, other: Vec<D, T>, t: Vec<D, T>) -> Vec<D, T> {
+        self + (other - self).combine(t, T::mul)
+    }
+
+    /// Get the point halfway between two vectors.
+    ///
+    /// Like `f64::midpoint`, the sum is halved, which keeps subnormal
+    /// components, and only when it overflows to infinity are the components
+    /// halved first. Only float vectors are supported, halving integers would
+    /// round the result.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(0.0, 0.0);
+    /// assert_eq!(a.midpoint(Vec2::new(4.0, 2.0)), (2.0, 1.0));
+    /// let max = Vec2::splat(f64::MAX);
+    /// assert_eq!(max.midpoint(max), max);
+    /// # }
+    /// ```
+    pub fn midpoint(self, other: Vec<D, T>) -> Vec<D, T> {
+        let half = (T::one() + T::one()).recip();
+        self.combine(other, |a, b| {
+            let sum = a + b;
+            if sum.is_finite() {
+                sum * half
+            } else {
+                a * half + b * half
+            }
+        })
+    }
+
+    /// Project the vector onto `other`.
+    ///
+    /// Projecting onto the zero vector divides zero by zero, so every component is NaN.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(3.0, 4.0);
+    /// let b = Vec2::new(2.0, 0.0);
+    /// assert_eq!(a.project_onto(b), (3.0, 0.0));
+    /// # }
+    /// ```
+    pub fn project_onto(self, other: Vec<D, T>) -> Vec<D, T> {
+        other * (self.dot(other) / other.dot(other))
+    }
+
+    /// Get the part of the vector orthogonal to `other`, the complement of [`Vec::project_onto`].
+    ///
+    /// Rejecting from the zero vector gives NaNs, like [`Vec::project_onto`].
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(3.0, 4.0);
+    /// let b = Vec2::new(2.0, 0.0);
+    /// assert_eq!(a.reject_from(b), (0.0, 4.0));
+    /// # }
+    /// ```
+    pub fn reject_from(self, other: Vec<D, T>) -> Vec<D, T> {
+        self - self.project_onto(other)
+    }
+
+    /// Reflect the vector off a surface with the given normal, which must be
+    /// unit length.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let ball = Vec2::new(1.0, -1.0);
+    /// let ground = Vec2::new(0.0, 1.0);
+    /// assert_eq!(ball.reflect(ground), (1.0, 1.0));
+    /// # }
+    /// ```
+    pub fn reflect(self, normal: Vec<D, T>) -> Vec<D, T> {
+        let two = T::one() + T::one();
+        self - normal * (two * self.dot(normal))
+    }
+
+    /// Refract the vector through a surface with the given normal, following
+    /// Snell's law. `eta` is the ratio of the refractive index of the medium
+    /// the vector comes from over the one it enters.
+    ///
+    /// Both the vector and the normal must be unit length, and the normal must
+    /// face the incoming vector, against its direction. The refracted vector is
+    /// then unit length too.
+    ///
+    /// Return `None` on total internal reflection, when the vector comes from
+    /// the denser medium at an angle too grazing to leave it.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let down = Vec2::new(0.0, -1.0);
+    /// let water = Vec2::new(0.0, 1.0);
+    /// // a vector hitting the surface head-on isn't bent
+    /// assert_eq!(down.refract(water, 1.0 / 1.33), Some(down));
+    ///
+    /// let grazing = Vec2::new(0.9, -0.1).normalized();
+    /// assert_eq!(grazing.refract(water, 1.33), None);
+    /// # }
+    /// ```
+    pub fn refract(self, normal: Vec<D, T>, eta: T) -> Option<Vec<D, T>> {
+        let one = T::one();
+        let cos = self.dot(normal);
+        let k = one - eta * eta * (one - cos * cos);
+        if k < T::zero() {
+            return None;
+        }
+        Some(self * eta - normal * (eta * cos + k.sqrt()))
+    }
+
+    /// Calculate the angle between two vectors, in radians.
+    ///
+    /// The cosine is clamped to `[-1, 1]` before calling `acos`, so rounding
+    /// errors on (anti)parallel vectors don't produce NaN.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(1.0_f32, 0.0);
+    /// let b = Vec2::new(0.0, 3.0);
+    /// let angle = a.angle_between(b);
+    /// assert!((angle - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    /// # }
+    /// ```
+    pub fn angle_between(self, other: Vec<D, T>) -> T {
+        let cos = self.dot(other) / (self.length() * other.length());
+        let cos = if cos < -T::one() {
+            -T::one()
+        } else if cos > T::one() {
+            T::one()
+        } else {
+            cos
+        };
+        cos.acos()
+    }
+
+    /// Check that every component is finite, neither infinite nor NaN.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// assert!(Vec2::new(1.0, -2.0).is_finite());
+    /// assert!(!Vec2::new(1.0, f64::NAN).is_finite());
+    /// assert!(!Vec2::new(f64::INFINITY, 0.0).is_finite());
+    /// # }
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.iter().all(|x| x.is_finite())
+    }
+
+    /// Check if any component is NaN.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let v = Vec2::new(0.0, 1.0);
+    /// assert!(!v.is_nan());
+    /// // zero divided by zero is NaN
+    /// assert!((v * 0.0 / 0.0).is_nan());
+    /// # }
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        self.iter().any(|x| x.is_nan())
+    }
+
+    /// Check that every component is normal: neither zero, infinite, subnormal nor NaN.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// assert!(Vec2::new(1.0, -2.0).is_normal());
+    /// assert!(!Vec2::new(1.0, 0.0).is_normal());
+    /// assert!(!Vec2::new(f64::NAN, 1.0).is_normal());
+    /// assert!(!Vec2::new(f64::MIN_POSITIVE / 2.0, 1.0).is_normal());
+    /// # }
+    /// ```
+    pub fn is_normal(&self) -> bool {
+        self.iter().all(|x| x.is_normal())
+    }
+
+    /// Check that each component differs from the one of `other` by at most `epsilon`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(0.1 + 0.2, 1.0);
+    /// let b = Vec2::new(0.3, 1.0);
+    /// assert_ne!(a, b);
+    /// assert!(a.abs_diff_eq(&b, 1e-9));
+    /// # }
+    /// ```
+    pub fn abs_diff_eq(&self, other: &Vec<D, T>, epsilon: T) -> bool {
+        zip(self.0, other.0).all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+
+    /// Check that each component is relatively close to the one of `other`.
+    ///
+    /// Two components are close if they differ by at most `epsilon`, which
+    /// handles values near zero, or by at most `max_relative` times the
+    /// largest of their absolute values.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(1000.0, 0.0);
+    /// let b = Vec2::new(1000.1, 1e-12);
+    /// assert!(a.relative_eq(&b, 1e-9, 1e-3));
+    /// assert!(!a.relative_eq(&b, 1e-9, 1e-6));
+    /// # }
+    /// ```
+    pub fn relative_eq(&self, other: &Vec<D, T>, epsilon: T, max_relative: T) -> bool {
+        zip(self.0, other.0).all(|(a, b)| {
+            let diff = (a - b).abs();
+            if diff <= epsilon {
+                return true;
+            }
+            let (a, b) = (a.abs(), b.abs());
+            let largest = if a > b { a } else { b };
+            diff <= largest * max_relative
+        })
+    }
+
+    /// Get the unit vector pointing in the same direction.
+    ///
+    /// A zero-length vector has no direction, it is returned unchanged
+    /// instead of producing NaNs.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(3.0_f32, 4.0);
+    /// assert_eq!(a.normalized(), (0.6, 0.8));
+    /// assert_eq!(Vec2::new(0.0_f32, 0.0).normalized(), (0.0, 0.0));
+    /// # }
+    /// ```
+    pub fn normalized(self) -> Self {
+        let length = self.length();
+        if length == T::zero() {
+            self
+        } else {
+            self / length
+        }
+    }
+
+    /// Normalize the vector in place, see [`Vec::normalized`].
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let mut a = Vec2::new(0.0_f32, 2.0);
+    /// a.normalize();
+    /// assert_eq!(a, (0.0, 1.0));
+    /// # }
+    /// ```
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    /// Scale the vector down to a length of `max` if it is longer, keeping its direction.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(6.0, 8.0);
+    /// assert_eq!(a.clamp_length_max(5.0), (3.0, 4.0));
+    /// assert_eq!(a.clamp_length_max(20.0), a);
+    /// # }
+    /// ```
+    pub fn clamp_length_max(self, max: T) -> Self {
+        let length = self.length();
+        if length > max {
+            self * (max / length)
+        } else {
+            self
+        }
+    }
+
+    /// Scale the vector so its length is between `min` and `max`, keeping its direction.
+    ///
+    /// A zero vector has no direction, it is returned unchanged even if `min`
+    /// is positive. `min` must be lower or equal to `max`, this is checked in
+    /// debug builds.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// assert_eq!(Vec2::new(6.0, 8.0).clamp_length(1.0, 5.0), (3.0, 4.0));
+    /// assert_eq!(Vec2::new(0.3, 0.4).clamp_length(1.0, 5.0), (0.6, 0.8));
+    /// assert_eq!(Vec2::new(0.0, 0.0).clamp_length(1.0, 5.0), (0.0, 0.0));
+    /// # }
+    /// ```
+    pub fn clamp_length(self, min: T, max: T) -> Self {
+        debug_assert!(min <= max, "clamp_length requires min <= max");
+        let length = self.length();
+        if length > max {
+            self * (max / length)
+        } else if length < min && length != T::zero() {
+            self * (min / length)
+        } else {
+            self
+        }
+    }
+
+    /// Move from `self` toward `target` by a distance of at most `max_delta`.
+    ///
+    /// When `target` is within `max_delta`, `target` itself is returned, so
+    /// repeated steps land exactly on it instead of overshooting.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(0.0, 0.0);
+    /// let target = Vec2::new(3.0, 4.0);
+    /// assert_eq!(a.move_towards(target, 2.5), (1.5, 2.0));
+    /// assert_eq!(a.move_towards(target, 10.0), target);
+    /// # }
+    /// ```
+    pub fn move_towards(self, target: Vec<D, T>, max_delta: T) -> Self {
+        if self.distance(target) <= max_delta {
+            target
+        } else {
+            self + (target - self).normalized() * max_delta
+        }
+    }
+
+    /// Clamp each component to `[0, 1]`, like the `saturate` of shaders.
+    ///
+    /// NaN components are kept as they are.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec3;
+    /// assert_eq!(Vec3::new(-0.5, 0.5, 2.0).saturate(), (0.0, 0.5, 1.0));
+    /// # }
+    /// ```
+    pub fn saturate(self) -> Self {
+        self.clamp_scalar(T::zero(), T::one())
+    }
+
+    /// Clamp each component to `[0, 1]`, an alias of [`Vec::saturate`].
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec3;
+    /// assert_eq!(Vec3::new(-0.5, 0.5, 2.0).clamp01(), (0.0, 0.5, 1.0));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn clamp01(self) -> Self {
+        self.saturate()
+    }
+
+    /// Round each component down to the largest integer lower or equal to it.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// assert_eq!(Vec2::new(1.7, -1.2).floor(), (1.0, -2.0));
+    /// # }
+    /// ```
+    pub fn floor(self) -> Self {
+        self.map(T::floor)
+    }
+
+    /// Round each component up to the smallest integer greater or equal to it.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// assert_eq!(Vec2::new(1.7, -1.2).ceil(), (2.0, -1.0));
+    /// # }
+    /// ```
+    pub fn ceil(self) -> Self {
+        self.map(T::ceil)
+    }
+
+    /// Round each component to the nearest integer, half-way cases away from zero.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec3;
+    /// assert_eq!(Vec3::new(1.5, -1.5, 0.4).round(), (2.0, -2.0, 0.0));
+    /// # }
+    /// ```
+    pub fn round(self) -> Self {
+        self.map(T::round)
+    }
+
+    /// Round each component toward zero, keeping its integer part.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// assert_eq!(Vec2::new(1.7, -1.7).trunc(), (1.0, -1.0));
+    /// # }
+    /// ```
+    pub fn trunc(self) -> Self {
+        self.map(T::trunc)
+    }
 
-impl<T, const D: usize> From<Vec<D, T>> for [T; D] {
-    fn from(x: Vec<D, T>) -> Self {
-        x.0
+    /// Get the fractional part of each component, `self - self.trunc()`.
+    ///
+    /// The fractional part has the sign of the component.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// assert_eq!(Vec2::new(1.75, -1.25).fract(), (0.75, -0.25));
+    /// # }
+    /// ```
+    pub fn fract(self) -> Self {
+        self.map(T::fract)
     }
-}
 
-// heart of most of the operations on Vec
-// TODO: Find faster way in debug mode to merge two statics arrays
-impl<T, const D: usize> Vec<D, T> {
-    /// Create a new vector from two other vectors and a combining function.
+    /// Get the square root of each component.
     ///
     /// # Example
     /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
     /// use isochro::vector::Vec2;
-    /// let a = Vec2::new(1, 2);
-    /// let b = Vec2::new(3, 4);
-    /// let c = Vec2::combine(a, b, |a, b| a.min(b));
-    /// assert_eq!(c.x, 1);
-    /// assert_eq!(c.y, 2);
+    /// assert_eq!(Vec2::new(4.0, 9.0).sqrt(), (2.0, 3.0));
+    /// # }
     /// ```
-    pub fn combine<U, R>(self, other: Vec<D, U>, f: impl Fn(T, U) -> R) -> Vec<D, R> {
-        let a = self.0.into_iter();
-        let b = other.0.into_iter();
-        let mut iter = zip(a, b).map(|(a, b)| f(a, b));
+    pub fn sqrt(self) -> Self {
+        self.map(T::sqrt)
+    }
 
-        Vec(std::array::from_fn(|_| unsafe {
-            iter.next().unwrap_unchecked()
-        }))
+    /// Get the reciprocal `1 / x` of each component.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// assert_eq!(Vec2::new(4.0, -0.5).recip(), (0.25, -2.0));
+    /// # }
+    /// ```
+    pub fn recip(self) -> Self {
+        self.map(T::recip)
     }
 
-    /// Create a new vector from a scalar and a combining function.
+    /// Raise each component to the floating point power `n`.
     ///
     /// # Example
     /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
     /// use isochro::vector::Vec2;
-    /// let a = Vec2::new(1, 2);
-    /// let b = Vec2::combine_scalar(a, 2, |a, b| a * b);
-    /// assert_eq!(b.x, 2);
-    /// assert_eq!(b.y, 4);
+    /// assert_eq!(Vec2::new(4.0, 9.0).powf(0.5), (2.0, 3.0));
+    /// # }
     /// ```
-    pub fn combine_scalar<U: Clone, R>(self, other: U, f: impl Fn(T, U) -> R) -> Vec<D, R> {
-        Vec(self.0.map(|a| f(a, other.clone())))
+    pub fn powf(self, n: T) -> Self {
+        self.combine_scalar(n, T::powf)
     }
 
-    /// Create a new vector from two other vectors and a combining function.
+    /// Compute `self * a + b` component by component, with a fused multiply-add
+    /// which rounds once instead of twice. It's usually faster too when the
+    /// target has a FMA instruction, but much slower otherwise.
     ///
     /// # Example
     /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
     /// use isochro::vector::Vec2;
-    /// let a = Vec2::new(1, 2);
-    /// let b = Vec2::new(3, 4);
-    /// let c = Vec2::combine_ref(a, &b, |a, b| a.min(*b)); // b is a reference
-    /// assert_eq!(c.x, 1);
-    /// assert_eq!(c.y, 2);
+    /// let v = Vec2::new(2.0, 3.0);
+    /// assert_eq!(v.mul_add(Vec2::new(4.0, 5.0), Vec2::new(1.0, -1.0)), (9.0, 14.0));
+    /// # }
     /// ```
-    pub fn combine_ref<U, R>(self, other: &Vec<D, U>, f: impl Fn(T, &U) -> R) -> Vec<D, R> {
-        let a = self.0.into_iter();
-        let b = other.0.iter();
-        let mut iter = zip(a, b).map(|(a, b)| f(a, b));
+    pub fn mul_add(self, a: Vec<D, T>, b: Vec<D, T>) -> Self {
+        self.combine3(a, b, T::mul_add)
+    }
 
-        Vec(std::array::from_fn(|_| unsafe {
-            iter.next().unwrap_unchecked()
-        }))
+    /// Get each component with the magnitude of the vector and the sign of the
+    /// matching component of `sign`, see [`Float::copysign`].
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec3;
+    /// let velocity = Vec3::new(2.0, -3.0, 4.0);
+    /// let direction = Vec3::new(-1.0, -0.5, 0.0);
+    /// assert_eq!(velocity.copysign(direction), (-2.0, -3.0, 4.0));
+    /// assert_eq!(velocity.copysign(Vec3::splat(-0.0)), (-2.0, -3.0, -4.0));
+    /// # }
+    /// ```
+    pub fn copysign(self, sign: Vec<D, T>) -> Self {
+        self.combine(sign, T::copysign)
     }
 
-    /// Create a new vector from a scalar and a combining function.
+    /// Raise each component to the power of the matching component of `exps`.
     ///
     /// # Example
     /// ```
-    /// use isochro::vector::Vec2;
-    /// let a = Vec2::new(1, 2);
-    /// let b = Vec2::combine_scalar_ref(&a, &2, |a, b| a * *b);
-    /// assert_eq!(b.x, 2);
-    /// assert_eq!(b.y, 4);
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec3;
+    /// let v = Vec3::new(4.0, 4.0, 4.0);
+    /// assert_eq!(v.powf(0.5), (2.0, 2.0, 2.0));
+    /// assert_eq!(v.component_pow(Vec3::new(0.5, 1.0, 2.0)), (2.0, 4.0, 16.0));
+    /// # }
     /// ```
-    pub fn combine_scalar_ref<U: Copy, R>(&self, other: U, f: impl Fn(&T, U) -> R) -> Vec<D, R> {
-        Vec(self.0.each_ref().map(|a| f(a, other)))
+    pub fn component_pow(self, exps: Vec<D, T>) -> Self {
+        self.combine(exps, T::powf)
     }
 
-    /// Create a new vector from two other vectors and a combining function.
+    /// Raise each component to the integer power `n`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec3;
+    /// assert_eq!(Vec3::new(2.0, -3.0, 0.5).powi(2), (4.0, 9.0, 0.25));
+    /// # }
+    /// ```
+    pub fn powi(self, n: i32) -> Self {
+        self.combine_scalar(n, T::powi)
+    }
+
+    /// Get the exponential `e^x` of each component.
     ///
     /// # Example
     /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
     /// use isochro::vector::Vec2;
-    /// let a = Vec2::new(1, 2);
-    /// let b = Vec2::new(3, 4);
-    /// let c = Vec2::combine_both_ref(&a, &b, |a, b| *a.min(b)); // a and b are references
-    /// assert_eq!(c, (1, 2));
+    /// let a = Vec2::new(0.0_f64, 1.0).exp();
+    /// assert!(a.abs_diff_eq(&Vec2::new(1.0, std::f64::consts::E), 1e-12));
+    /// # }
     /// ```
-    pub fn combine_both_ref<U, R>(&self, other: &Vec<D, U>, f: impl Fn(&T, &U) -> R) -> Vec<D, R> {
-        let a = self.0.iter();
-        let b = other.0.iter();
-        let mut iter = zip(a, b).map(|(a, b)| f(a, b));
+    pub fn exp(self) -> Self {
+        self.map(T::exp)
+    }
 
-        Vec(std::array::from_fn(|_| unsafe {
-            iter.next().unwrap_unchecked()
-        }))
+    /// Get the natural logarithm of each component.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(1.0_f64, std::f64::consts::E).ln();
+    /// assert!(a.abs_diff_eq(&Vec2::new(0.0, 1.0), 1e-12));
+    /// # }
+    /// ```
+    pub fn ln(self) -> Self {
+        self.map(T::ln)
     }
 
-    /// Create a new vector from two other vectors and a combining function.
+    /// Get the sine of each component, in radians.
+    ///
     /// # Example
     /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
     /// use isochro::vector::Vec2;
-    /// let mut a = Vec2::new(1, 2);
-    /// let b = Vec2::new(3, 4);
-    /// a.combine_assign(b, |mut a, b| *a += b);
-    /// assert_eq!(a.x, 4);
-    /// assert_eq!(a.y, 6);
+    /// let a = Vec2::new(0.0_f64, std::f64::consts::FRAC_PI_2).sin();
+    /// assert!(a.abs_diff_eq(&Vec2::new(0.0, 1.0), 1e-12));
+    /// # }
     /// ```
-    pub fn combine_assign<U>(&mut self, other: Vec<D, U>, f: impl Fn(&mut T, U)) {
-        let mut b = other.0.into_iter();
-        for a in self.0.iter_mut() {
-            unsafe { f(a, b.next().unwrap_unchecked()) }
-        }
+    pub fn sin(self) -> Self {
+        self.map(T::sin)
     }
 
-    /// Create a new vector from a scalar and a combining function.
+    /// Get the cosine of each component, in radians.
+    ///
     /// # Example
     /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
     /// use isochro::vector::Vec2;
-    /// let mut a = Vec2::new(1, 2);
-    /// a.combine_assign_scalar(2, |a, b| *a = *a * b);
-    /// assert_eq!(a.x, 2);
-    /// assert_eq!(a.y, 4);
+    /// let a = Vec2::new(0.0_f64, std::f64::consts::PI).cos();
+    /// assert!(a.abs_diff_eq(&Vec2::new(1.0, -1.0), 1e-12));
+    /// # }
     /// ```
-    pub fn combine_assign_scalar<U: Clone>(&mut self, other: U, f: impl Fn(&mut T, U)) {
-        for a in self.0.iter_mut() {
-            f(a, other.clone());
-        }
+    pub fn cos(self) -> Self {
+        self.map(T::cos)
     }
 
-    /// Create a new vector from two other vectors and a combining function.
+    /// Get the tangent of each component, in radians.
+    ///
     /// # Example
     /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
     /// use isochro::vector::Vec2;
-    /// let mut a = Vec2::new(1, 2);
-    /// let b = Vec2::new(3, 4);
-    /// a.combine_assign_ref(&b, |mut a, b| *a += b);
-    /// assert_eq!(a.x, 4);
-    /// assert_eq!(a.y, 6);
+    /// let a = Vec2::new(0.0_f64, std::f64::consts::FRAC_PI_4).tan();
+    /// assert!(a.abs_diff_eq(&Vec2::new(0.0, 1.0), 1e-12));
+    /// # }
     /// ```
-    pub fn combine_assign_ref<U>(&mut self, other: &Vec<D, U>, f: impl Fn(&mut T, &U)) {
-        let mut b = other.0.iter();
-        for a in self.0.iter_mut() {
-            unsafe { f(a, b.next().unwrap_unchecked()) }
-        }
+    pub fn tan(self) -> Self {
+        self.map(T::tan)
     }
 }
 
@@ -223,7 +1859,7 @@ where
     /// assert_eq!(c.y, 6);
     /// ```
     fn add(self, rhs: Vec<D, U>) -> Self::Output {
-        self.combine(rhs, T::add)
+        self.simd_add(rhs)
     }
 }
 
@@ -291,7 +1927,7 @@ where
     /// assert_eq!(c.y, -2);
     /// ```
     fn sub(self, rhs: Vec<D, U>) -> Self::Output {
-        self.combine(rhs, |a, b| a - b)
+        self.simd_sub(rhs)
     }
 }
 
@@ -339,6 +1975,45 @@ where
     }
 }
 
+// negation
+impl<T, R, const D: usize> Neg for Vec<D, T>
+where
+    T: Neg<Output = R>,
+{
+    type Output = Vec<D, R>;
+
+    /// Negate every component of a vector.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = -Vec2::new(1, 2);
+    /// assert_eq!(a, (-1, -2));
+    /// ```
+    fn neg(self) -> Self::Output {
+        Vec(self.0.map(T::neg))
+    }
+}
+
+impl<T, R, const D: usize> Neg for &Vec<D, T>
+where
+    for<'a> &'a T: Neg<Output = R>,
+{
+    type Output = Vec<D, R>;
+
+    /// Negate every component of a vector.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::new(1, 2);
+    /// assert_eq!(-&a, (-1, -2));
+    /// ```
+    fn neg(self) -> Self::Output {
+        Vec(self.0.each_ref().map(|a| -a))
+    }
+}
+
 // multiplication
 impl<T, U, R, const D: usize> Mul<U> for Vec<D, T>
 where
@@ -358,7 +2033,7 @@ where
     /// assert_eq!(b.y, 4);
     /// ```
     fn mul(self, rhs: U) -> Self::Output {
-        self.combine_scalar(rhs, T::mul)
+        self.simd_mul_scalar(rhs)
     }
 }
 
@@ -469,6 +2144,71 @@ where
     }
 }
 
+impl<T, U, R, const D: usize> Rem<U> for Vec<D, T>
+where
+    T: Rem<U, Output = R>,
+    U: Clone,
+{
+    type Output = Vec<D, R>;
+
+    /// Get the remainder of the division of a vector by a scalar.
+    ///
+    /// This is the `%` of each component: for integers the remainder has the sign
+    /// of the dividend, so `-7 % 3 == -1`. Use `rem_euclid` through [`Vec::map`]
+    /// to wrap negative coordinates on a grid.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// assert_eq!(Vec3::new(5, 6, 7) % 3, (2, 0, 1));
+    /// assert_eq!(Vec3::new(-7, 7, -1) % 3, (-1, 1, -1));
+    /// assert_eq!(Vec3::new(-7, 7, -1).map(|x: i32| x.rem_euclid(3)), (2, 1, 2));
+    /// assert_eq!(Vec3::new(5.5, -1.5, 2.0) % 2.0, (1.5, -1.5, 0.0));
+    /// ```
+    fn rem(self, rhs: U) -> Self::Output {
+        self.combine_scalar(rhs, T::rem)
+    }
+}
+
+impl<T, U, R, const D: usize> Rem<U> for &Vec<D, T>
+where
+    for<'a> &'a T: Rem<U, Output = R>,
+    U: Copy,
+{
+    type Output = Vec<D, R>;
+
+    /// Get the remainder of the division of a vector by a scalar.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let a = Vec3::new(5, 6, 7);
+    /// assert_eq!(&a % 3, (2, 0, 1));
+    /// ```
+    fn rem(self, rhs: U) -> Self::Output {
+        self.combine_scalar_ref(rhs, |a, b| a % b)
+    }
+}
+
+impl<T, U, const D: usize> RemAssign<U> for Vec<D, T>
+where
+    T: RemAssign<U>,
+    U: Clone,
+{
+    /// Replace each component of a vector by its remainder of the division by a scalar.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// let mut a = Vec3::new(5, 6, 7);
+    /// a %= 3;
+    /// assert_eq!(a, (2, 0, 1));
+    /// ```
+    fn rem_assign(&mut self, rhs: U) {
+        self.combine_assign_scalar(rhs, T::rem_assign);
+    }
+}
+
 // dot product
 impl<T, U, R, const D: usize> DotProduct<Vec<D, U>> for Vec<D, T>
 where
@@ -481,17 +2221,30 @@ where
     ///
     /// # Example
     /// ```
-    /// use isochro::vector::{DotProduct, Vec3};
+    /// use isochro::ops::DotProduct;
+    /// use isochro::vector::Vec3;
     /// let a = Vec3::new(1, 2, 3);
     /// let b = Vec3::new(4, 5, 6);
     /// let c = a.dot(b);
     /// assert_eq!(c, 4 + 10 + 18);
+    /// ```
+    ///
+    /// The dot product of zero-dimensional vectors has no value to return,
+    /// so it is rejected at compile time:
+    /// ```compile_fail
+    /// use isochro::ops::DotProduct;
+    /// use isochro::vector::Vec;
+    /// let a = Vec::<0, i32>([]);
+    /// let c = a.dot(a);
+    /// ```
     fn dot(self, rhs: Vec<D, U>) -> Self::Output {
-        use core::iter::Iterator;
-        let result = zip(self.0.into_iter(), rhs.0.into_iter())
-            .map(|(a, b)| a * b)
-            .reduce(|acc, x| acc + x);
-        unsafe { result.unwrap_unchecked() }
+        const {
+            assert!(
+                D > 0,
+                "the dot product of zero-dimensional vectors is undefined"
+            )
+        };
+        self.simd_dot(rhs)
     }
 }
 
@@ -515,4 +2268,324 @@ mod tests {
         assert_eq!(vec[2], vec.z);
         assert_eq!(vec[3], vec.w);
     }
+
+    #[test]
+    fn test_vec_swizzle() {
+        let vec3 = Vec3::new(1, 2, 3);
+        assert_eq!(vec3.xz(), (1, 3));
+        assert_eq!(vec3.zyx(), (3, 2, 1));
+
+        let vec4 = Vec4::new(1, 2, 3, 4);
+        assert_eq!(vec4.zw(), (3, 4));
+        assert_eq!(vec4.xyw(), (1, 2, 4));
+        assert_eq!(vec4.wzyx(), (4, 3, 2, 1));
+    }
+
+    #[test]
+    fn test_vec_lexicographic_order() {
+        use std::collections::BTreeMap;
+
+        let mut grid = BTreeMap::new();
+        grid.insert(Vec2::new(1, 0), "east");
+        grid.insert(Vec2::new(0, 1), "north");
+        grid.insert(Vec2::new(0, 0), "origin");
+        let keys: std::vec::Vec<_> = grid.keys().copied().collect();
+        assert_eq!(keys, [Vec2::new(0, 0), Vec2::new(0, 1), Vec2::new(1, 0)]);
+
+        let a = Vec2::new(0, 9);
+        let b = Vec2::new(1, 0);
+        assert_eq!(Ord::min(a, b), a);
+        assert_eq!(a.min(b), (0, 0));
+        assert_eq!(
+            Vec2::new(1.0, f64::NAN).partial_cmp(&Vec2::new(1.0, 0.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_vec_hash_map_key() {
+        use std::collections::HashMap;
+
+        let mut grid = HashMap::new();
+        grid.insert(Vec2::new(0, 0), "origin");
+        grid.insert(Vec2::new(1, 0), "east");
+        grid.insert(Vec2::new(0, 1), "north");
+        grid.insert(Vec2::new(0, 0), "start");
+
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid[&Vec2::new(0, 0)], "start");
+        assert_eq!(grid.get(&Vec2::new(1, 0)), Some(&"east"));
+        assert_eq!(grid.get(&Vec2::new(1, 1)), None);
+    }
+
+    #[test]
+    fn test_vec_empty_reductions() {
+        let empty = Vec::<0, i32>([]);
+        assert_eq!(empty.sum(), 0);
+        assert_eq!(empty.product(), 1);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_vec_move_towards() {
+        let target = Vec3::new(1.0, 2.0, 2.0);
+        let mut position = Vec3::new(0.0, 0.0, 0.0);
+        position = position.move_towards(target, 2.0);
+        assert!(position.abs_diff_eq(&(target * (2.0 / 3.0)), 1e-12));
+        // the remaining distance is 1, stepping by 2 must stop on the target
+        position = position.move_towards(target, 2.0);
+        assert_eq!(position, target);
+        assert_eq!(position.move_towards(target, 2.0), target);
+        assert_eq!(position.move_towards(target, 0.0), target);
+    }
+
+    #[test]
+    fn test_vec_empty_mask() {
+        let empty: Vec<0, bool> = Vec([]);
+        assert!(empty.all());
+        assert!(!empty.any());
+        assert_eq!(empty.count_true(), 0);
+    }
+
+    #[test]
+    fn test_vec_accessors() {
+        let mut vec = Vec4::new(1, 2, 3, 4);
+        assert_eq!((vec.x(), vec.y(), vec.z(), vec.w()), (1, 2, 3, 4));
+        vec.set_x(5);
+        vec.set_w(8);
+        assert_eq!(vec, (5, 2, 3, 8));
+
+        // the getter copies, so the vector can be mutated while the value is kept
+        let mut vec = Vec3::new(1.0, 2.0, 3.0);
+        let y = vec.y();
+        vec.set_z(y * 2.0);
+        vec *= 2.0;
+        assert_eq!(vec, (2.0, 4.0, 8.0));
+
+        let mut vec = Vec2::new('a', 'b');
+        vec.set_y(vec.x());
+        assert_eq!(vec, ('a', 'a'));
+
+        let vec = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(vec.with_y(0.0), (1.0, 0.0, 3.0));
+        assert_eq!(vec.with_x(0.0).with_z(0.0), (0.0, 2.0, 0.0));
+        assert_eq!(Vec4::new(1, 2, 3, 4).with_w(0).truncate(), (1, 2, 3));
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_vec_mul_add() {
+        let v = Vec3::new(0.1, 1.0 / 3.0, 1e8);
+        let a = Vec3::new(10.0, 3.0, 1e8 + 1.0);
+        let b = Vec3::new(-1.0, -1.0, -1e16);
+        let fused = v.mul_add(a, b);
+        let naive = v.component_mul(a) + b;
+        assert!(fused.abs_diff_eq(&naive, 1e-12), "{fused:?} {naive:?}");
+        // 0.1 * 10 rounds to exactly 1 when done separately, not when fused
+        assert_eq!(naive[0], 0.0);
+        assert_eq!(fused[0], 0.1f64.mul_add(10.0, -1.0));
+        assert_ne!(fused[0], 0.0);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_vec_midpoint() {
+        let tiny = Vec2::splat(5e-324);
+        assert_eq!(tiny.midpoint(tiny), tiny);
+        assert_eq!(tiny.midpoint(Vec2::splat(0.0)), (0.0, 0.0));
+        let max = Vec2::new(f64::MAX, -f64::MAX);
+        assert_eq!(max.midpoint(max), max);
+        assert_eq!(max.midpoint(-max), (0.0, 0.0));
+        assert_eq!(
+            Vec2::new(1.0, f64::MAX).midpoint(Vec2::new(3.0, 5e-324)),
+            (2.0, f64::MAX / 2.0)
+        );
+        let inf = Vec2::new(f64::INFINITY, f64::INFINITY);
+        assert_eq!(inf.midpoint(Vec2::new(1.0, f64::INFINITY)), inf);
+        assert!(inf.midpoint(-inf).is_nan());
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_vec_norms() {
+        let v = Vec4::new(1.0, -2.0, 0.0, 2.0);
+        assert_eq!(v.norm(1.0), 5.0);
+        assert_eq!(v.norm(2.0), 3.0);
+        assert_eq!(v.norm(2.0), v.length());
+        assert_eq!(v.norm_inf(), 2.0);
+        // the p-norm decreases toward the infinity norm as p grows
+        let (l3, l10) = (v.norm(3.0), v.norm(10.0));
+        assert!(3.0 > l3 && l3 > l10 && l10 > 2.0, "{l3} {l10}");
+        assert!((v.norm(100.0) - 2.0).abs() < 0.02);
+
+        let empty: Vec<0, f64> = Vec([]);
+        assert_eq!(empty.norm_inf(), 0.0);
+
+        let nan = Vec2::new(f64::NAN, 1.0);
+        assert!(nan.norm_inf().is_nan());
+        assert!(nan.norm(2.0).is_nan());
+        assert!(Vec2::new(1.0, f64::NAN).norm_inf().is_nan());
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_vec_refract() {
+        // from air into glass at 45 degrees, sin(t) = sin(i) / 1.5
+        let incident = Vec2::new(1.0, -1.0).normalized();
+        let normal = Vec2::new(0.0, 1.0);
+        let refracted = incident.refract(normal, 1.0 / 1.5).unwrap();
+        let sin = core::f64::consts::FRAC_1_SQRT_2 / 1.5;
+        let expected = Vec2::new(sin, -(1.0 - sin * sin).sqrt());
+        assert!(refracted.abs_diff_eq(&expected, 1e-12), "{refracted:?}");
+        assert!((refracted.length() - 1.0).abs() < 1e-12);
+
+        // and out of the parallel bottom face of the glass, to the original direction
+        let out = refracted.refract(normal, 1.5).unwrap();
+        assert!(out.abs_diff_eq(&incident, 1e-12), "{out:?}");
+
+        // the critical angle of glass is about 41.8 degrees
+        let critical = (1.0_f64 / 1.5).asin();
+        let below = Vec2::new((critical - 0.01).sin(), (critical - 0.01).cos());
+        let above = Vec2::new((critical + 0.01).sin(), (critical + 0.01).cos());
+        assert!(below.refract(normal * -1.0, 1.5).is_some());
+        assert_eq!(above.refract(normal * -1.0, 1.5), None);
+
+        assert_eq!(
+            incident.reflect(normal),
+            incident.component_mul(Vec2::new(1.0, -1.0))
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_vec2_rotate() {
+        use crate::matrix::Mat;
+        use core::f64::consts::{FRAC_PI_2, PI};
+
+        let v = Vec2::new(1.0, 0.0).rotate(FRAC_PI_2);
+        assert!(v.abs_diff_eq(&Vec2::new(0.0, 1.0), 1e-12), "{v:?}");
+        let v = Vec2::new(1.0, 0.0).rotate(-FRAC_PI_2);
+        assert!(v.abs_diff_eq(&Vec2::new(0.0, -1.0), 1e-12), "{v:?}");
+
+        let v = Vec2::new(3.0, -4.0);
+        let rotated = v.rotate(0.7);
+        assert!(rotated.abs_diff_eq(&(Mat::rotation(0.7) * v), 1e-12));
+        assert!((rotated.length() - 5.0).abs() < 1e-12);
+
+        let pivot = Vec2::new(2.0, 3.0);
+        assert_eq!(pivot.rotate_around(pivot, 1.0), pivot);
+        let v = Vec2::new(2.0, 5.0).rotate_around(pivot, PI);
+        assert!(v.abs_diff_eq(&Vec2::new(2.0, 1.0), 1e-12), "{v:?}");
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_barycentric() {
+        let (a, b, c) = (
+            Vec2::new(1.0, 1.0),
+            Vec2::new(4.0, 2.0),
+            Vec2::new(2.0, 5.0),
+        );
+        let centroid = (a + b + c) / 3.0;
+        let third = 1.0 / 3.0;
+        let weights = barycentric(centroid, a, b, c).unwrap();
+        assert!(
+            weights.abs_diff_eq(&Vec3::splat(third), 1e-12),
+            "{weights:?}"
+        );
+        // the winding order doesn't matter
+        let weights = barycentric(centroid, a, c, b).unwrap();
+        assert!(
+            weights.abs_diff_eq(&Vec3::splat(third), 1e-12),
+            "{weights:?}"
+        );
+
+        assert_eq!(barycentric(a, a, b, c), Some(Vec3::new(1.0, 0.0, 0.0)));
+        assert_eq!(barycentric(c, a, b, c), Some(Vec3::new(0.0, 0.0, 1.0)));
+        let outside = barycentric(a * 2.0 - b, a, b, c).unwrap();
+        assert!(
+            outside.abs_diff_eq(&Vec3::new(2.0, -1.0, 0.0), 1e-12),
+            "{outside:?}"
+        );
+        let p = a * 0.2 + b * 0.5 + c * 0.3;
+        let weights = barycentric(p, a, b, c).unwrap();
+        assert!(
+            weights.abs_diff_eq(&Vec3::new(0.2, 0.5, 0.3), 1e-12),
+            "{weights:?}"
+        );
+
+        assert_eq!(barycentric(centroid, a, a, a), None);
+        assert_eq!(barycentric(centroid, a, b, b * 2.0 - a), None);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_vec_orthonormal_basis() {
+        use crate::ops::CrossProduct;
+
+        let normals = [
+            Vec3::new(0.0_f64, 0.0, 1.0),
+            Vec3::new(-1.0, 0.0, 0.0),
+            Vec3::new(1.0, 2.0, 3.0).normalized(),
+            Vec3::new(-0.3, 1.0, 0.01).normalized(),
+            Vec3::new(1.0, 1.0, 1.0).normalized(),
+        ];
+        for n in normals {
+            let (a, b) = n.orthonormal_basis();
+            for (u, v) in [(a, b), (a, n), (b, n)] {
+                assert!(u.dot(v).abs() < 1e-12, "{n:?}: {u:?} . {v:?}");
+            }
+            assert!((a.length() - 1.0).abs() < 1e-12, "{a:?}");
+            assert!((b.length() - 1.0).abs() < 1e-12, "{b:?}");
+            assert!(a.cross(b).abs_diff_eq(&n, 1e-12), "{n:?}");
+        }
+    }
+
+    #[test]
+    fn test_vec_swizzle_set() {
+        let mut state = Vec4::new(1, 2, 3, 4);
+        state.set_xy(Vec2::new(5, 6));
+        assert_eq!(state, (5, 6, 3, 4));
+        state.set_xyz(Vec3::new(7, 8, 9));
+        assert_eq!(state, (7, 8, 9, 4));
+
+        let mut position = Vec3::new(1.0, 2.0, 3.0);
+        position.set_xy(position.xy() * 2.0);
+        assert_eq!(position, (2.0, 4.0, 3.0));
+
+        // the components don't need to be Copy
+        let mut names = Vec3::new(String::from("a"), String::from("b"), String::from("c"));
+        names.set_xy(Vec2::new(String::from("d"), String::from("e")));
+        assert_eq!(names.z, "c");
+        assert_eq!(names.x, "d");
+    }
+
+    #[test]
+    fn test_vec_into_iter() {
+        let mut vec = Vec3::new(1, 2, 3);
+        for x in &mut vec {
+            *x += 1;
+        }
+        let by_ref: std::vec::Vec<&i32> = (&vec).into_iter().collect();
+        assert_eq!(by_ref, [&2, &3, &4]);
+        let by_value: std::vec::Vec<i32> = vec.into_iter().collect();
+        assert_eq!(by_value, [2, 3, 4]);
+    }
+
+    #[test]
+    fn test_vec_neg_ref_without_copy() {
+        #[derive(Debug, PartialEq)]
+        struct Big(i32);
+
+        impl Neg for &Big {
+            type Output = Big;
+            fn neg(self) -> Big {
+                Big(-self.0)
+            }
+        }
+
+        let a = Vec2::new(Big(1), Big(-2));
+        assert_eq!(-&a, Vec2::new(Big(-1), Big(2)));
+        assert_eq!(a, Vec2::new(Big(1), Big(-2)));
+    }
 }
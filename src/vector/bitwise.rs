@@ -0,0 +1,219 @@
+//! Bitwise operators, applied component by component.
+//!
+//! `&`, `|` and `^` take either another vector or a scalar of the element type,
+//! the scalar version is implemented for the primitive integer types and `bool`.
+//! `<<` and `>>` shift every component by a scalar.
+
+use core::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+
+use crate::macros::forward_ref_binop;
+use crate::vector::Vec;
+
+macro_rules! impl_bit_binop {
+    ($trait:ident, $method:ident, $op:tt, $a:literal, $b:literal, $expected:literal) => {
+        impl<T, U, R, const D: usize> $trait<Vec<D, U>> for Vec<D, T>
+        where
+            T: $trait<U, Output = R>,
+        {
+            type Output = Vec<D, R>;
+
+            #[doc = concat!("Apply `", stringify!($op), "` to the components of two vectors.")]
+            ///
+            /// # Example
+            /// ```
+            /// use isochro::vector::Vec2;
+            #[doc = concat!("let a = Vec2::<u8>::new(", $a, ");")]
+            #[doc = concat!("let b = Vec2::<u8>::new(", $b, ");")]
+            #[doc = concat!("assert_eq!(a ", stringify!($op), " b, (", $expected, "));")]
+            #[doc = concat!("assert_eq!(&a ", stringify!($op), " &b, (", $expected, "));")]
+            /// ```
+            fn $method(self, rhs: Vec<D, U>) -> Self::Output {
+                self.combine(rhs, T::$method)
+            }
+        }
+
+        forward_ref_binop! {
+            impl<T, U, R; const D: usize> $trait<Vec<D, U>>, $method for Vec<D, T>
+            where
+                T: $trait<U, Output = R> + Copy,
+                U: Copy,
+        }
+    };
+}
+
+impl_bit_binop!(BitAnd, bitand, &, "0b1100, 0xff", "0b1010, 0x0f", "0b1000, 0x0f");
+impl_bit_binop!(BitOr, bitor, |, "0b1100, 0xf0", "0b1010, 0x0f", "0b1110, 0xff");
+impl_bit_binop!(BitXor, bitxor, ^, "0b1100, 0xff", "0b1010, 0x0f", "0b0110, 0xf0");
+
+macro_rules! impl_bit_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl_bit_scalar!(@op $t, BitAnd, bitand);
+            impl_bit_scalar!(@op $t, BitOr, bitor);
+            impl_bit_scalar!(@op $t, BitXor, bitxor);
+        )*
+    };
+    (@op $t:ty, $trait:ident, $method:ident) => {
+        impl<const D: usize> $trait<$t> for Vec<D, $t> {
+            type Output = Vec<D, $t>;
+
+            /// Apply the operator between each component and a scalar.
+            fn $method(self, rhs: $t) -> Self::Output {
+                self.combine_scalar(rhs, <$t>::$method)
+            }
+        }
+
+        forward_ref_binop! {
+            impl<; const D: usize> $trait<$t>, $method for Vec<D, $t>
+        }
+    };
+}
+
+impl_bit_scalar!(
+    bool, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+impl<T, R, const D: usize> Not for Vec<D, T>
+where
+    T: Not<Output = R>,
+{
+    type Output = Vec<D, R>;
+
+    /// Apply `!` to every component of a vector, a bitwise not for integers.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::<u8>::new(0b1111_0000, 0);
+    /// assert_eq!(!a, (0b0000_1111, 0xff));
+    /// assert_eq!(!Vec2::new(true, false), (false, true));
+    /// ```
+    fn not(self) -> Self::Output {
+        Vec(self.0.map(T::not))
+    }
+}
+
+impl<T, R, const D: usize> Not for &Vec<D, T>
+where
+    for<'a> &'a T: Not<Output = R>,
+{
+    type Output = Vec<D, R>;
+
+    /// Apply `!` to every component of a vector, a bitwise not for integers.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::<u8>::new(0b1111_0000, 0);
+    /// assert_eq!(!&a, (0b0000_1111, 0xff));
+    /// ```
+    fn not(self) -> Self::Output {
+        Vec(self.0.each_ref().map(|a| !a))
+    }
+}
+
+impl<T, U, R, const D: usize> Shl<U> for Vec<D, T>
+where
+    T: Shl<U, Output = R>,
+    U: Clone,
+{
+    type Output = Vec<D, R>;
+
+    /// Shift every component of a vector to the left.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::<u8>::new(0b0000_0011, 0b0100_0001);
+    /// assert_eq!(a << 2u32, (0b0000_1100, 0b0000_0100));
+    /// ```
+    fn shl(self, rhs: U) -> Self::Output {
+        self.combine_scalar(rhs, T::shl)
+    }
+}
+
+impl<T, U, R, const D: usize> Shl<U> for &Vec<D, T>
+where
+    for<'a> &'a T: Shl<U, Output = R>,
+    U: Copy,
+{
+    type Output = Vec<D, R>;
+
+    /// Shift every component of a vector to the left.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::<u8>::new(0b0000_0011, 0b0100_0001);
+    /// assert_eq!(&a << 2u32, (0b0000_1100, 0b0000_0100));
+    /// ```
+    fn shl(self, rhs: U) -> Self::Output {
+        self.combine_scalar_ref(rhs, |a, b| a << b)
+    }
+}
+
+impl<T, U, R, const D: usize> Shr<U> for Vec<D, T>
+where
+    T: Shr<U, Output = R>,
+    U: Clone,
+{
+    type Output = Vec<D, R>;
+
+    /// Shift every component of a vector to the right.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::<u8>::new(0b0000_1100, 0b1000_0010);
+    /// assert_eq!(a >> 2u32, (0b0000_0011, 0b0010_0000));
+    /// ```
+    fn shr(self, rhs: U) -> Self::Output {
+        self.combine_scalar(rhs, T::shr)
+    }
+}
+
+impl<T, U, R, const D: usize> Shr<U> for &Vec<D, T>
+where
+    for<'a> &'a T: Shr<U, Output = R>,
+    U: Copy,
+{
+    type Output = Vec<D, R>;
+
+    /// Shift every component of a vector to the right.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let a = Vec2::<u8>::new(0b0000_1100, 0b1000_0010);
+    /// assert_eq!(&a >> 2u32, (0b0000_0011, 0b0010_0000));
+    /// ```
+    fn shr(self, rhs: U) -> Self::Output {
+        self.combine_scalar_ref(rhs, |a, b| a >> b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vector::{Vec2, Vec3};
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_bit_scalar() {
+        let flags = Vec3::<u32>::new(0b101, 0b011, 0b110);
+        assert_eq!(flags & 0b001, (0b001, 0b001, 0b000));
+        assert_eq!(flags | 0b001, (0b101, 0b011, 0b111));
+        assert_eq!(flags ^ 0b111, (0b010, 0b100, 0b001));
+        assert_eq!(&flags & 0b001, flags & 0b001);
+        assert_eq!(flags | &0b001, flags | 0b001);
+        assert_eq!(&flags ^ &0b111, flags ^ 0b111);
+        assert_eq!(Vec2::new(true, false) & true, (true, false));
+    }
+
+    #[test]
+    fn test_bit_mask() {
+        let a = Vec3::new(1, 5, 3);
+        let b = Vec3::new(2, 2, 3);
+        assert_eq!(a.cmplt(b) | a.cmpeq(b), a.cmple(b));
+        assert_eq!(!a.cmplt(b), a.cmpge(b));
+    }
+}
@@ -0,0 +1,177 @@
+//! Dispatch of the element-wise vector operators.
+//!
+//! The operators call the traits of this module instead of `combine` directly.
+//! Their generic impls use `combine`, and with the nightly `simd` feature they
+//! are specialized with `core::simd` for `f32` and `f64` vectors of 2, 4, 8 and
+//! 16 components. Any other vector falls back to the generic impl.
+
+use crate::vector::Vec;
+use core::iter::zip;
+use core::ops::{Add, Mul, Sub};
+
+pub(crate) trait SimdAdd<Rhs> {
+    type Output;
+
+    fn simd_add(self, rhs: Rhs) -> Self::Output;
+}
+
+pub(crate) trait SimdSub<Rhs> {
+    type Output;
+
+    fn simd_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+pub(crate) trait SimdMulScalar<Rhs> {
+    type Output;
+
+    fn simd_mul_scalar(self, rhs: Rhs) -> Self::Output;
+}
+
+pub(crate) trait SimdDot<Rhs> {
+    type Output;
+
+    /// Must only be called with `D > 0`.
+    fn simd_dot(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Implement the generic fallbacks, `default` makes them specializable.
+macro_rules! impl_fallback {
+    ($($default:tt)?) => {
+        impl<T, U, R, const D: usize> SimdAdd<Vec<D, U>> for Vec<D, T>
+        where
+            T: Add<U, Output = R>,
+        {
+            type Output = Vec<D, R>;
+
+            #[inline]
+            $($default)? fn simd_add(self, rhs: Vec<D, U>) -> Vec<D, R> {
+                self.combine(rhs, T::add)
+            }
+        }
+
+        impl<T, U, R, const D: usize> SimdSub<Vec<D, U>> for Vec<D, T>
+        where
+            T: Sub<U, Output = R>,
+        {
+            type Output = Vec<D, R>;
+
+            #[inline]
+            $($default)? fn simd_sub(self, rhs: Vec<D, U>) -> Vec<D, R> {
+                self.combine(rhs, T::sub)
+            }
+        }
+
+        impl<T, U, R, const D: usize> SimdMulScalar<U> for Vec<D, T>
+        where
+            T: Mul<U, Output = R>,
+            U: Clone,
+        {
+            type Output = Vec<D, R>;
+
+            #[inline]
+            $($default)? fn simd_mul_scalar(self, rhs: U) -> Vec<D, R> {
+                self.combine_scalar(rhs, T::mul)
+            }
+        }
+
+        impl<T, U, R, const D: usize> SimdDot<Vec<D, U>> for Vec<D, T>
+        where
+            T: Mul<U, Output = R>,
+            R: Add<R, Output = R>,
+        {
+            type Output = R;
+
+            #[inline]
+            $($default)? fn simd_dot(self, rhs: Vec<D, U>) -> R {
+                let mut products = zip(self.0, rhs.0).map(|(a, b)| a * b);
+                let first = products.next().expect("D > 0 is checked by the caller");
+                products.fold(first, |acc, x| acc + x)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "simd"))]
+impl_fallback!();
+#[cfg(feature = "simd")]
+impl_fallback!(default);
+
+#[cfg(feature = "simd")]
+macro_rules! impl_simd {
+    ($t:ty => $($lanes:literal),*) => {
+        $(
+            impl SimdAdd<Vec<$lanes, $t>> for Vec<$lanes, $t> {
+                #[inline]
+                fn simd_add(self, rhs: Vec<$lanes, $t>) -> Vec<$lanes, $t> {
+                    Vec((Simd::from_array(self.0) + Simd::from_array(rhs.0)).to_array())
+                }
+            }
+
+            impl SimdSub<Vec<$lanes, $t>> for Vec<$lanes, $t> {
+                #[inline]
+                fn simd_sub(self, rhs: Vec<$lanes, $t>) -> Vec<$lanes, $t> {
+                    Vec((Simd::from_array(self.0) - Simd::from_array(rhs.0)).to_array())
+                }
+            }
+
+            impl SimdMulScalar<$t> for Vec<$lanes, $t> {
+                #[inline]
+                fn simd_mul_scalar(self, rhs: $t) -> Vec<$lanes, $t> {
+                    Vec((Simd::from_array(self.0) * Simd::splat(rhs)).to_array())
+                }
+            }
+
+            impl SimdDot<Vec<$lanes, $t>> for Vec<$lanes, $t> {
+                /// The products are summed pairwise, the rounding can differ
+                /// from the generic left to right sum.
+                #[inline]
+                fn simd_dot(self, rhs: Vec<$lanes, $t>) -> $t {
+                    (Simd::from_array(self.0) * Simd::from_array(rhs.0)).reduce_sum()
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "simd")]
+use core::simd::{Simd, num::SimdFloat};
+
+#[cfg(feature = "simd")]
+impl_simd!(f32 => 2, 4, 8, 16);
+#[cfg(feature = "simd")]
+impl_simd!(f64 => 2, 4, 8, 16);
+
+#[cfg(all(test, feature = "simd"))]
+mod tests {
+    use crate::ops::DotProduct;
+    use crate::vector::Vec;
+
+    fn check<const D: usize>() {
+        let a: Vec<D, f32> = Vec(core::array::from_fn(|i| i as f32 * 1.5 - 3.0));
+        let b: Vec<D, f32> = Vec(core::array::from_fn(|i| 0.25 - i as f32));
+        assert_eq!(a + b, a.combine(b, |a, b| a + b));
+        assert_eq!(a - b, a.combine(b, |a, b| a - b));
+        assert_eq!(a * 0.3, a.combine_scalar(0.3, |a, b| a * b));
+        // small exact products, so the summation order can't change the result
+        let scalar_dot = a.0.iter().zip(b.0).map(|(a, b)| a * b).sum::<f32>();
+        assert_eq!(a.dot(b), scalar_dot);
+
+        let a = a.map(f64::from);
+        let b = b.map(f64::from);
+        assert_eq!(a + b, a.combine(b, |a, b| a + b));
+        assert_eq!(a - b, a.combine(b, |a, b| a - b));
+        assert_eq!(a * 0.3, a.combine_scalar(0.3, |a, b| a * b));
+        let scalar_dot = a.0.iter().zip(b.0).map(|(a, b)| a * b).sum::<f64>();
+        assert_eq!(a.dot(b), scalar_dot);
+    }
+
+    #[test]
+    fn test_simd_matches_scalar() {
+        check::<2>();
+        check::<4>();
+        check::<8>();
+        check::<16>();
+        // not a SIMD width, uses the generic path
+        check::<3>();
+    }
+}
@@ -1,58 +1,137 @@
-use std::ops::{Deref, DerefMut};
+use core::ops::{Mul, Neg, Sub};
+
+use crate::macros::{accessors, window_deref};
+use crate::scalar::Float;
+use crate::vector::Vec3;
 
 /// A shorthand for a 2-dimensional vector.
 pub type Vec2<T> = crate::vector::Vec<2, T>;
 
-/// This structure isn't used directly, but is used to provide a nicer way to access the fields of a Vec2.
-///
-/// # Example
-/// ```
-/// use isochro::vector::Vec2;
-///
-/// let vec = Vec2::new(1, 2);
-/// assert_eq!(vec.x, 1);
-/// assert_eq!(vec.y, 2);
-/// ```
-#[repr(C)]
-pub struct Window2<T> {
-    pub x: T,
-    pub y: T,
+crate::window! {
+    /// This structure isn't used directly, but is used to provide a nicer way to access the fields of a Vec2.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    ///
+    /// let vec = Vec2::new(1, 2);
+    /// assert_eq!(vec.x, 1);
+    /// assert_eq!(vec.y, 2);
+    /// ```
+    pub struct Window2<2> { x, y }
 }
 
 impl<T> Vec2<T> {
-    pub fn new(x: T, y: T) -> Self {
+    /// Create a vector from its components.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// const RIGHT: Vec2<f32> = Vec2::new(1.0, 0.0);
+    /// assert_eq!(RIGHT.x, 1.0);
+    /// ```
+    pub const fn new(x: T, y: T) -> Self {
         Self([x, y])
     }
-}
 
-impl<T> Deref for Vec2<T> {
-    type Target = Window2<T>;
+    accessors! {
+        Vec2(1, 2);
+        x, set_x, with_x => 0: (9, 2),
+        y, set_y, with_y => 1: (1, 9),
+    }
+
+    /// Create a 3-dimensional vector by appending a `z` component.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::{Vec2, Vec3};
+    /// assert_eq!(Vec2::new(1, 2).extend(3), Vec3::new(1, 2, 3));
+    /// ```
+    pub fn extend(self, z: T) -> Vec3<T> {
+        let [x, y] = self.0;
+        Vec3::new(x, y, z)
+    }
+}
 
-    fn deref(&self) -> &Self::Target {
-        assert_eq!(size_of::<Vec2<T>>(), size_of::<Window2<T>>());
-        union Transform2<'a, T> {
-            src: &'a Vec2<T>,
-            dst: &'a Window2<T>,
-        }
+impl<T> Vec2<T>
+where
+    T: Mul<Output = T> + Sub<Output = T> + Neg<Output = T> + Copy,
+{
+    /// Get the vector rotated by a quarter turn counterclockwise, `(-y, x)`.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let right = Vec2::new(1, 0);
+    /// assert_eq!(right.perp(), (0, 1));
+    /// assert_eq!(right.perp().perp(), -right);
+    /// ```
+    pub fn perp(self) -> Vec2<T> {
+        let [x, y] = self.0;
+        Vec2::new(-y, x)
+    }
 
-        let cast = Transform2 { src: self };
-        unsafe { cast.dst } //should we be concerned about this?
+    /// Calculate the perpendicular dot product `self.x * other.y - self.y * other.x`,
+    /// the 2D analogue of the cross product.
+    ///
+    /// It's the dot product of `self.perp()` and `other`, and twice the signed area
+    /// of the triangle formed by the two vectors: positive when `other` is
+    /// counterclockwise from `self`, negative when clockwise and zero when they
+    /// are parallel.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec2;
+    /// let right = Vec2::new(2, 0);
+    /// let up = Vec2::new(0, 3);
+    /// assert_eq!(right.perp_dot(up), 6);
+    /// assert_eq!(up.perp_dot(right), -6);
+    /// assert_eq!(right.perp_dot(right * 4), 0);
+    /// ```
+    pub fn perp_dot(self, other: Vec2<T>) -> T {
+        let [x, y] = self.0;
+        let [ox, oy] = other.0;
+        x * oy - y * ox
     }
 }
 
-impl<T> DerefMut for Vec2<T> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        assert_eq!(size_of::<Vec2<T>>(), size_of::<Window2<T>>());
-        union Transform2<'a, T> {
-            src: &'a mut Vec2<T>,
-            dst: &'a mut Window2<T>,
-        }
+impl<T: Float> Vec2<T> {
+    /// Rotate the vector counterclockwise by `angle` radians around the origin,
+    /// like [`Mat::rotation`](crate::matrix::Mat::rotation) without building
+    /// the matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let v = Vec2::new(1.0_f32, 0.0).rotate(std::f32::consts::FRAC_PI_2);
+    /// assert!(v.abs_diff_eq(&Vec2::new(0.0, 1.0), 1e-6));
+    /// # }
+    /// ```
+    pub fn rotate(self, angle: T) -> Vec2<T> {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let [x, y] = self.0;
+        Vec2::new(x * cos - y * sin, x * sin + y * cos)
+    }
 
-        let cast = Transform2 { src: self };
-        unsafe { cast.dst } // SAFETY: repr(C) guarantees that the fields are in the same order
+    /// Rotate the point counterclockwise by `angle` radians around `pivot`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::vector::Vec2;
+    /// let pivot = Vec2::new(1.0_f32, 1.0);
+    /// let v = Vec2::new(2.0, 1.0).rotate_around(pivot, std::f32::consts::PI);
+    /// assert!(v.abs_diff_eq(&Vec2::new(0.0, 1.0), 1e-6));
+    /// # }
+    /// ```
+    pub fn rotate_around(self, pivot: Vec2<T>, angle: T) -> Vec2<T> {
+        pivot + (self - pivot).rotate(angle)
     }
 }
 
+window_deref!(Window2<2>);
+
 impl<T: PartialEq> PartialEq<(T, T)> for Vec2<T> {
     fn eq(&self, other: &(T, T)) -> bool {
         self[0] == other.0 && self[1] == other.1
@@ -64,3 +143,36 @@ impl<T> From<(T, T)> for Vec2<T> {
         Self([value.0, value.1])
     }
 }
+
+/// Calculate the barycentric coordinates `(u, v, w)` of the point `p` in the
+/// triangle `a`, `b`, `c`, such that `p == a * u + b * v + c * w` and
+/// `u + v + w == 1`.
+///
+/// The point is inside the triangle when the three weights are in `[0, 1]`, a
+/// negative weight means it's on the other side of the opposite edge. The
+/// triangle can be in either winding order.
+///
+/// Return `None` if the triangle is degenerate, with its three vertices on a
+/// line or at the same place, as the weights aren't unique then. Nearly
+/// degenerate triangles give huge weights.
+///
+/// # Example
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use isochro::vector::{Vec2, Vec3, barycentric};
+/// let (a, b, c) = (Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0), Vec2::new(0.0, 2.0));
+/// assert_eq!(barycentric(Vec2::new(2.0, 1.0), a, b, c), Some(Vec3::new(0.0, 0.5, 0.5)));
+/// assert_eq!(barycentric(Vec2::new(1.0, 1.0), a, b, b * 2.0), None);
+/// # }
+/// ```
+pub fn barycentric<T: Float>(p: Vec2<T>, a: Vec2<T>, b: Vec2<T>, c: Vec2<T>) -> Option<Vec3<T>> {
+    // twice the signed areas of the whole triangle and of two sub-triangles
+    let (ab, ac, ap) = (b - a, c - a, p - a);
+    let area = ab.perp_dot(ac);
+    if area == T::zero() {
+        return None;
+    }
+    let v = ap.perp_dot(ac) / area;
+    let w = ab.perp_dot(ap) / area;
+    Some(Vec3::new(T::one() - v - w, v, w))
+}
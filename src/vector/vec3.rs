@@ -1,57 +1,131 @@
-use std::ops::{Deref, DerefMut};
+use core::ops::{Mul, Sub};
+
+use crate::macros::{accessors, forward_ref_binop, swizzle, swizzle_set, window_deref};
+use crate::ops::CrossProduct;
+use crate::scalar::Float;
+use crate::vector::{Vec2, Vec4};
 
 /// A shorthand for a 3-dimensional vector.
 pub type Vec3<T> = crate::vector::Vec<3, T>;
 
-/// This structure isn't used directly, but is used to provide a nicer way to access the fields of a Vec3.
-///
-/// # Example
-/// ```
-/// use isochro::vector::Vec3;
-///
-/// let vec = Vec3::new(1, 2, 3);
-/// assert_eq!(vec.x, 1);
-/// assert_eq!(vec.y, 2);
-/// assert_eq!(vec.z, 3);
-/// ```
-#[repr(C)]
-pub struct Window3<T> {
-    pub x: T,
-    pub y: T,
-    pub z: T,
+crate::window! {
+    /// This structure isn't used directly, but is used to provide a nicer way to access the fields of a Vec3.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    ///
+    /// let vec = Vec3::new(1, 2, 3);
+    /// assert_eq!(vec.x, 1);
+    /// assert_eq!(vec.y, 2);
+    /// assert_eq!(vec.z, 3);
+    /// ```
+    pub struct Window3<3> { x, y, z }
 }
 
 impl<T> Vec3<T> {
-    pub fn new(x: T, y: T, z: T) -> Self {
+    /// Create a vector from its components.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec3;
+    /// const UP: Vec3<f32> = Vec3::new(0.0, 1.0, 0.0);
+    /// assert_eq!(UP.y, 1.0);
+    /// ```
+    pub const fn new(x: T, y: T, z: T) -> Self {
         Self([x, y, z])
     }
-}
 
-impl<T> Deref for Vec3<T> {
-    type Target = Window3<T>;
-    fn deref(&self) -> &Self::Target {
-        union Transform3<'a, T> {
-            src: &'a Vec3<T>,
-            dst: &'a Window3<T>,
-        }
+    accessors! {
+        Vec3(1, 2, 3);
+        x, set_x, with_x => 0: (9, 2, 3),
+        y, set_y, with_y => 1: (1, 9, 3),
+        z, set_z, with_z => 2: (1, 2, 9),
+    }
+
+    /// Create a 4-dimensional vector by appending a `w` component.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::{Vec3, Vec4};
+    /// assert_eq!(Vec3::new(1, 2, 3).extend(4), Vec4::new(1, 2, 3, 4));
+    /// ```
+    pub fn extend(self, w: T) -> Vec4<T> {
+        let [x, y, z] = self.0;
+        Vec4::new(x, y, z, w)
+    }
+
+    /// Create a 2-dimensional vector by dropping the `z` component.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::{Vec2, Vec3};
+    /// assert_eq!(Vec3::new(1, 2, 3).truncate(), Vec2::new(1, 2));
+    /// ```
+    pub fn truncate(self) -> Vec2<T> {
+        let [x, y, _] = self.0;
+        Vec2::new(x, y)
+    }
+
+    swizzle! {
+        xy => Vec2[0, 1],
+        xz => Vec2[0, 2],
+        yx => Vec2[1, 0],
+        yz => Vec2[1, 2],
+        zx => Vec2[2, 0],
+        zy => Vec2[2, 1],
+        xyz => Vec3[0, 1, 2],
+        xzy => Vec3[0, 2, 1],
+        yxz => Vec3[1, 0, 2],
+        yzx => Vec3[1, 2, 0],
+        zxy => Vec3[2, 0, 1],
+        zyx => Vec3[2, 1, 0],
+    }
 
-        let cast = Transform3 { src: self };
-        unsafe { cast.dst } // SAFETY: repr(C) guarantees that the fields are in the same order
+    swizzle_set! {
+        set_xy => Vec2[0, 1],
     }
 }
 
-impl<T> DerefMut for Vec3<T> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        union Transform3<'a, T> {
-            src: &'a mut Vec3<T>,
-            dst: &'a mut Window3<T>,
-        }
+impl<T: Float> Vec3<T> {
+    /// Get two unit vectors orthogonal to the vector and to each other, such as
+    /// two tangents of a surface from its normal. The vector must be unit length.
+    ///
+    /// The vector is crossed with the axis it's the least aligned with, so the
+    /// result stays precise whatever its direction. `(a, b, self)` is a
+    /// right-handed basis: `a.cross(b) == self`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(any(feature = "std", feature = "libm"))] {
+    /// use isochro::ops::CrossProduct;
+    /// use isochro::vector::Vec3;
+    /// let normal = Vec3::new(0.0, 0.0, 1.0);
+    /// let (a, b) = normal.orthonormal_basis();
+    /// assert_eq!(a.cross(b), normal);
+    /// assert_eq!(a.z, 0.0);
+    /// # }
+    /// ```
+    pub fn orthonormal_basis(self) -> (Vec3<T>, Vec3<T>) {
+        let abs = self.abs();
+        let mut axis = Vec3::splat(T::zero());
+        let least = if abs[0] <= abs[1] && abs[0] <= abs[2] {
+            0
+        } else if abs[1] <= abs[2] {
+            1
+        } else {
+            2
+        };
+        axis[least] = T::one();
 
-        let cast = Transform3 { src: self };
-        unsafe { cast.dst } //should we be concerned about this?
+        let a = self.cross(axis).normalized();
+        let b = self.cross(a);
+        (a, b)
     }
 }
 
+window_deref!(Window3<3>);
+
 impl<T: PartialEq> PartialEq<(T, T, T)> for Vec3<T> {
     fn eq(&self, other: &(T, T, T)) -> bool {
         self[0] == other.0 && self[1] == other.1 && self[2] == other.2
@@ -63,3 +137,34 @@ impl<T> From<(T, T, T)> for Vec3<T> {
         Self::new(tuple.0, tuple.1, tuple.2)
     }
 }
+
+impl<T> CrossProduct<Vec3<T>> for Vec3<T>
+where
+    T: Mul<Output = T> + Sub<Output = T> + Copy,
+{
+    type Output = Vec3<T>;
+
+    /// Calculate the cross product of two vectors, following the right-hand rule.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::ops::CrossProduct;
+    /// use isochro::vector::Vec3;
+    /// let a = Vec3::new(1, 0, 0);
+    /// let b = Vec3::new(0, 1, 0);
+    /// assert_eq!(a.cross(b), Vec3::new(0, 0, 1));
+    /// assert_eq!(b.cross(a), (0, 0, -1));
+    /// assert_eq!((&a).cross(&b), (0, 0, 1)); // works on references too
+    /// ```
+    fn cross(self, other: Vec3<T>) -> Self::Output {
+        let [ax, ay, az] = self.0;
+        let [bx, by, bz] = other.0;
+        Vec3::new(ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx)
+    }
+}
+
+forward_ref_binop! {
+    impl<T> CrossProduct<Vec3<T>>, cross for Vec3<T>
+    where
+        T: Mul<Output = T> + Sub<Output = T> + Copy,
+}
@@ -1,59 +1,123 @@
-use std::ops::{Deref, DerefMut};
+use crate::macros::{accessors, swizzle, swizzle_set, window_deref};
+use crate::vector::{Vec2, Vec3};
 
 /// A shorthand for a 4-dimensional vector.
 pub type Vec4<T> = crate::vector::Vec<4, T>;
 
-/// This structure isn't used directly, but is used to provide a nicer way to access the fields of a Vec4.
-///
-/// # Example
-/// ```
-/// use isochro::vector::Vec4;
-///
-/// let vec = Vec4::new(1, 2, 3, 4);
-/// assert_eq!(vec.x, 1);
-/// assert_eq!(vec.y, 2);
-/// assert_eq!(vec.z, 3);
-/// assert_eq!(vec.w, 4);
-/// ```
-#[repr(C)]
-pub struct Window4<T> {
-    pub x: T,
-    pub y: T,
-    pub z: T,
-    pub w: T,
+crate::window! {
+    /// This structure isn't used directly, but is used to provide a nicer way to access the fields of a Vec4.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec4;
+    ///
+    /// let vec = Vec4::new(1, 2, 3, 4);
+    /// assert_eq!(vec.x, 1);
+    /// assert_eq!(vec.y, 2);
+    /// assert_eq!(vec.z, 3);
+    /// assert_eq!(vec.w, 4);
+    /// ```
+    pub struct Window4<4> { x, y, z, w }
 }
 
 impl<T> Vec4<T> {
-    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+    /// Create a vector from its components.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec4;
+    /// const WHITE: Vec4<u8> = Vec4::new(255, 255, 255, 255);
+    /// assert_eq!(WHITE.w, 255);
+    /// ```
+    pub const fn new(x: T, y: T, z: T, w: T) -> Self {
         Self([x, y, z, w])
     }
-}
 
-impl<T> Deref for Vec4<T> {
-    type Target = Window4<T>;
-    fn deref(&self) -> &Self::Target {
-        union Transform4<'a, T> {
-            src: &'a Vec4<T>,
-            dst: &'a Window4<T>,
-        }
+    accessors! {
+        Vec4(1, 2, 3, 4);
+        x, set_x, with_x => 0: (9, 2, 3, 4),
+        y, set_y, with_y => 1: (1, 9, 3, 4),
+        z, set_z, with_z => 2: (1, 2, 9, 4),
+        w, set_w, with_w => 3: (1, 2, 3, 9),
+    }
+
+    /// Create a 3-dimensional vector by dropping the `w` component.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::{Vec3, Vec4};
+    /// assert_eq!(Vec4::new(1, 2, 3, 4).truncate(), Vec3::new(1, 2, 3));
+    /// ```
+    pub fn truncate(self) -> Vec3<T> {
+        let [x, y, z, _] = self.0;
+        Vec3::new(x, y, z)
+    }
+
+    swizzle! {
+        xy => Vec2[0, 1],
+        xz => Vec2[0, 2],
+        xw => Vec2[0, 3],
+        yz => Vec2[1, 2],
+        yw => Vec2[1, 3],
+        zw => Vec2[2, 3],
+        xyz => Vec3[0, 1, 2],
+        xyw => Vec3[0, 1, 3],
+        xzw => Vec3[0, 2, 3],
+        yzw => Vec3[1, 2, 3],
+        zyx => Vec3[2, 1, 0],
+        wzyx => Vec4[3, 2, 1, 0],
+    }
 
-        let cast = Transform4 { src: self };
-        unsafe { cast.dst } // SAFETY: repr(C) guarantees that the fields are in the same order
+    swizzle_set! {
+        set_xy => Vec2[0, 1],
+        set_xyz => Vec3[0, 1, 2],
     }
 }
 
-impl<T> DerefMut for Vec4<T> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        union Transform4<'a, T> {
-            src: &'a mut Vec4<T>,
-            dst: &'a mut Window4<T>,
-        }
+/// Colors packed in a `u32`, the components being the `r, g, b, a` channels.
+impl Vec4<u8> {
+    /// Unpack a color stored as `0xRRGGBBAA`, red in the most significant byte.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec4;
+    /// let orange = Vec4::from_rgba_u32(0xff8000c0);
+    /// assert_eq!(orange, (0xff, 0x80, 0x00, 0xc0));
+    /// assert_eq!(orange.to_rgba_u32(), 0xff8000c0);
+    /// ```
+    pub const fn from_rgba_u32(rgba: u32) -> Self {
+        Self(rgba.to_be_bytes())
+    }
+
+    /// Pack the color as `0xRRGGBBAA`, red in the most significant byte.
+    pub const fn to_rgba_u32(self) -> u32 {
+        u32::from_be_bytes(self.0)
+    }
 
-        let cast = Transform4 { src: self };
-        unsafe { cast.dst } // SAFETY: repr(C) guarantees that the fields are in the same order
+    /// Unpack a color stored as `0xAARRGGBB`, alpha in the most significant byte.
+    ///
+    /// # Example
+    /// ```
+    /// use isochro::vector::Vec4;
+    /// let orange = Vec4::from_argb_u32(0xc0ff8000);
+    /// assert_eq!(orange, (0xff, 0x80, 0x00, 0xc0));
+    /// assert_eq!(orange.to_argb_u32(), 0xc0ff8000);
+    /// assert_eq!(orange.to_rgba_u32(), 0xff8000c0);
+    /// ```
+    pub const fn from_argb_u32(argb: u32) -> Self {
+        let [a, r, g, b] = argb.to_be_bytes();
+        Self([r, g, b, a])
+    }
+
+    /// Pack the color as `0xAARRGGBB`, alpha in the most significant byte.
+    pub const fn to_argb_u32(self) -> u32 {
+        let [r, g, b, a] = self.0;
+        u32::from_be_bytes([a, r, g, b])
     }
 }
 
+window_deref!(Window4<4>);
+
 impl<T: PartialEq> PartialEq<(T, T, T, T)> for Vec4<T> {
     fn eq(&self, other: &(T, T, T, T)) -> bool {
         self[0] == other.0 && self[1] == other.1 && self[2] == other.2 && self[3] == other.3